@@ -22,13 +22,12 @@ async fn main() {
         Err(err) => panic!("Response error: {}\n", err),
     };
 
-    // Check if there is an error.
+    // Split the response into the data, the error, and the cache data.
     // An error "No such user!" will be returned here if the user does not exist.
-    if let Some(err) = response.error {
-        panic!("Error: {}\n", err.msg.expect("no error message"));
-    }
-
-    let data = response.data.unwrap();
+    let data = match response.split() {
+        (Some(data), _, _) => data,
+        (None, err, _) => panic!("Error: {:?}\n", err.and_then(|e| e.msg)),
+    };
     println!("Name: {}", data.username);
     println!("ID: {}", data.id);
     println!("XP: {}", data.xp);
@@ -13,7 +13,6 @@ async fn main() {
     let client = Client::new();
 
     // Set the social connection to search for.
-    // The API document says searching for the other social links will be added in the near future.
     let social_connection = SocialConnection::Discord("724976600873041940".to_string());
 
     // Search for the account.
@@ -26,6 +25,11 @@ async fn main() {
         panic!("Error: {}\n", err.msg.expect("no error message"));
     }
 
+    if !response.found() {
+        println!("No user found.");
+        return;
+    }
+
     let data = response.data.unwrap().user.unwrap();
     println!("Name: {}", data.username);
     println!("ID: {}", data.id);
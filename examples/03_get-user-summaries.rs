@@ -41,7 +41,7 @@ async fn main() {
     // WARNING: Consider whether you really need to use this method.
     //          If you only collect data for one or two game modes,
     //          use the methods for the individual summaries instead.
-    let _ = client.get_user_all_summaries(user); // .await.unwrap();
+    // let _ = client.get_user_all_summaries(user).await.unwrap();
 
     // For more information about the data structures, see:
     // - 40 LINES: https://docs.rs/tetr_ch/latest/tetr_ch/model/summary/forty_lines/struct.FortyLines.html
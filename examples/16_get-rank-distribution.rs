@@ -0,0 +1,27 @@
+//! Gets the amount of players per TETRA LEAGUE rank.
+//!
+//! Run the following Cargo command to run this example:
+//!
+//! ```bash
+//! cargo run --example 16_get-rank-distribution
+//! ```
+
+use tetr_ch::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    let client = Client::new();
+
+    // Get the rank distribution.
+    let distribution = match client.get_rank_distribution().await {
+        Ok(d) => d,
+        Err(err) => panic!("Response error: {}\n", err),
+    };
+
+    // Print the rank pyramid, from the highest rank.
+    let max = distribution.values().copied().max().unwrap_or(0).max(1);
+    for (rank, count) in distribution.iter().rev() {
+        let bar = "#".repeat((count * 50 / max) as usize);
+        println!("{:>3} {:>7} {}", rank.name(), count, bar);
+    }
+}
@@ -1,23 +1,27 @@
 //! A module for the [`Client`] struct and supporting types.
 
 use self::{
-    error::{ClientCreationError, RspErr},
+    config::ClientConfig,
+    error::{ClientCreationError, ResponseError, RspErr},
     param::{
         news_stream::ToNewsStreamParam,
         record::{self, Gamemode},
         record_leaderboard::{self, RecordsLeaderboardId},
         search_user::SocialConnection,
+        summary::SummaryKind,
         user_leaderboard::{self, LeaderboardType},
     },
-    response::process_response,
+    response::{process_json, process_response},
+    transport::Transport,
 };
 use crate::{
     model::{
         achievement_info::AchievementInfo,
+        comparison::{ComparedUser, UserComparison},
         labs::{
             league_ranks::LabsLeagueRanks, leagueflow::LabsLeagueflow, scoreflow::LabsScoreflow,
         },
-        leaderboard::{HistoricalLeaderboard, Leaderboard},
+        leaderboard::{HistoricalLeaderboard, Leaderboard, PastUserWithPrisecter},
         news::NewsItems,
         records_leaderboard::RecordsLeaderboard,
         response::Response,
@@ -26,15 +30,29 @@ use crate::{
         server_stats::ServerStats,
         summary::{
             blitz::Blitz, forty_lines::FortyLines, league::LeagueDataWrap, record::Record,
-            zen::Zen, zenith::Zenith, AllSummaries,
+            zen::Zen, zenith::Zenith, AllSummaries, PartialSummaries,
         },
         user::User,
         user_records::UserRecords,
-        util::Achievement,
+        util::{Achievement, Rank, UserId},
     },
-    util::{encode, validate_limit},
+    util::{
+        clock::{Clock, SystemClock},
+        encode, is_valid_user_id, validate_limit,
+    },
+};
+use futures_util::{
+    future::{self, join_all, try_join4},
+    stream::{self, StreamExt, TryStreamExt},
+    try_join,
 };
 use reqwest::header;
+use std::future::Future;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 use uuid::Uuid;
 
 const API_URL: &str = "https://ch.tetr.io/api/";
@@ -58,11 +76,21 @@ const API_URL: &str = "https://ch.tetr.io/api/";
 /// ```
 ///
 /// [See more examples](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples)
+///
+/// # Reusing a client
+///
+/// Each client created by [`Client::new`] etc. has its own connection pool.
+/// So create a client once and reuse it, instead of creating a new one for each request.
+/// Cloning a client is cheap and the clones share the connection pool and the session ID,
+/// so it can be passed around (e.g. to other tasks) freely.
+#[derive(Clone)]
 #[non_exhaustive]
-#[derive(Default)]
 pub struct Client {
     client: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    clock: Arc<dyn Clock + Send + Sync>,
     x_session_id: Option<String>,
+    api_url: String,
 }
 
 impl Client {
@@ -80,6 +108,12 @@ impl Client {
     //!   Even if the HTTP request failed,
     //!   it may be possible to deserialize the response containing an error message,
     //!   so the deserialization will be tried before returning this error.
+    //! - A [`ResponseError::UnexpectedContentType`](crate::client::error::ResponseError::UnexpectedContentType) is returned,
+    //!   if the response was not JSON (e.g. an HTML error page during maintenance).
+
+    /// The maximum number of the requests sent at a time
+    /// by the methods that send many requests (e.g. [`Client::usernames_for`]).
+    pub const MAX_CONCURRENT_REQUESTS: usize = 8;
 
     /// Creates a new [`Client`].
     ///
@@ -92,9 +126,13 @@ impl Client {
     /// let client = Client::new();
     /// ```
     pub fn new() -> Self {
+        let client = reqwest::Client::new();
         Self {
-            client: reqwest::Client::new(),
+            transport: Arc::new(client.clone()),
+            clock: Arc::new(SystemClock),
+            client,
             x_session_id: None,
+            api_url: API_URL.to_string(),
         }
     }
 
@@ -130,19 +168,79 @@ impl Client {
         } else {
             Uuid::new_v4().to_string()
         };
-        match header::HeaderValue::from_str(&session_id) {
-            Ok(hv) => {
-                let mut headers = header::HeaderMap::new();
-                headers.insert("X-Session-ID", hv);
-                match reqwest::Client::builder().default_headers(headers).build() {
-                    Ok(client) => Ok(Self {
-                        client,
-                        x_session_id: Some(session_id),
-                    }),
-                    Err(e) => Err(ClientCreationError::BuildErr(e)),
-                }
+        if header::HeaderValue::from_str(&session_id).is_err() {
+            return Err(ClientCreationError::InvalidHeaderValue(session_id));
+        }
+        match reqwest::Client::builder().build() {
+            Ok(client) => Ok(Self {
+                transport: Arc::new(client.clone()),
+                clock: Arc::new(SystemClock),
+                client,
+                x_session_id: Some(session_id),
+                api_url: API_URL.to_string(),
+            }),
+            Err(e) => Err(ClientCreationError::BuildErr(e)),
+        }
+    }
+
+    /// Creates a new [`Client`] with the specified configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `config` - The configuration of the client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::{client::config::ClientConfig, prelude::*};
+    ///
+    /// # fn main() -> Result<(), tetr_ch::client::error::ClientCreationError> {
+    /// let config = ClientConfig {
+    ///     timeout_ms: Some(10000),
+    ///     ..Default::default()
+    /// };
+    /// // Create a new client with the configuration.
+    /// let client = Client::from_config(config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - A [`ClientCreationError::InvalidHeaderValue`] is returned,
+    ///   if the session ID contains invalid characters.
+    ///   Only visible ASCII characters (32-127) are permitted.
+    /// - A [`ClientCreationError::BuildErr`] is returned,
+    ///   if failed to build the client.
+    pub fn from_config(config: ClientConfig) -> Result<Self, ClientCreationError> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(session_id) = &config.session_id {
+            if header::HeaderValue::from_str(session_id).is_err() {
+                return Err(ClientCreationError::InvalidHeaderValue(
+                    session_id.to_string(),
+                ));
             }
-            Err(_) => Err(ClientCreationError::InvalidHeaderValue(session_id)),
+        }
+        if let Some(timeout_ms) = config.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout_ms));
+        }
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(config.gzip);
+        }
+        #[cfg(feature = "brotli")]
+        {
+            builder = builder.brotli(config.brotli);
+        }
+        match builder.build() {
+            Ok(client) => Ok(Self {
+                transport: Arc::new(client.clone()),
+                clock: Arc::new(SystemClock),
+                client,
+                x_session_id: config.session_id,
+                api_url: config.api_url,
+            }),
+            Err(e) => Err(ClientCreationError::BuildErr(e)),
         }
     }
 
@@ -151,6 +249,90 @@ impl Client {
         self.x_session_id.as_deref()
     }
 
+    /// Returns a new [`Client`] that shares the session ID and the underlying HTTP client.
+    ///
+    /// This is the same as cloning the client, and it is cheap,
+    /// since the underlying HTTP client is reference-counted.
+    /// So the derived clients can be passed around
+    /// without losing the session consistency (e.g. for pagination).
+    ///
+    /// Note that the API asks to use a different session ID for unrelated requests.
+    /// To start a new session, create a new client with [`Client::with_session_id`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # fn main() -> Result<(), tetr_ch::client::error::ClientCreationError> {
+    /// let client = Client::with_session_id(None)?;
+    /// let derived = client.reuse_session();
+    /// assert_eq!(derived.session_id(), client.session_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reuse_session(&self) -> Client {
+        self.clone()
+    }
+
+    /// Returns the [`Client`] that sends the requests with the specified transport
+    /// instead of the default HTTP client.
+    ///
+    /// This is useful for testing the code that uses the client without a live server.
+    ///
+    /// # Arguments
+    ///
+    /// - `transport` - The transport to send the requests with.
+    ///
+    /// # Examples
+    ///
+    /// See [`Transport`].
+    pub fn with_transport(self, transport: impl Transport + 'static) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            ..self
+        }
+    }
+
+    /// Returns the [`Client`] that uses the specified clock
+    /// instead of the system time.
+    ///
+    /// The clock is used to check whether the cache of a response has expired
+    /// (e.g. in [`Client::revalidate_user`]),
+    /// so the cache expiry can be tested deterministically.
+    ///
+    /// # Arguments
+    ///
+    /// - `clock` - The clock to get the current time from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::{prelude::*, util::clock::FixedClock};
+    ///
+    /// let client = Client::new().with_clock(FixedClock(1661710769000));
+    /// ```
+    pub fn with_clock(self, clock: impl Clock + Send + Sync + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            ..self
+        }
+    }
+
+    /// Builds the request and sends it with the transport.
+    ///
+    /// The `X-Session-ID` header is set here, not as a default header of the HTTP client,
+    /// so it is sent with any transport.
+    async fn send(
+        &self,
+        mut request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        if let Some(session_id) = &self.x_session_id {
+            request = request.header("X-Session-ID", session_id);
+        }
+        self.transport.send(request.build()?).await
+    }
+
     /// Gets the detailed information about the specified user.
     ///
     /// About the endpoint "User Info",
@@ -172,66 +354,70 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_user(&self, user: &str) -> RspErr<Response<User>> {
-        let url = format!("{}users/{}", API_URL, encode(user.to_lowercase()));
-        let res = self.client.get(url).send().await;
+        let url = build_url(&self.api_url, "users/{}", &[&user.to_lowercase()]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
-    /// Searches for a TETR.IO user account by the social connection.
+    /// Gets the detailed information about the user with the specified user ID.
     ///
-    /// About the endpoint "User Search",
-    /// see the [API document](https://tetr.io/about/api/#userssearchquery).
+    /// Unlike [`Client::get_user`], the argument is not lowercased,
+    /// and it is validated as a user ID before the request is sent.
+    ///
+    /// About the endpoint "User Info",
+    /// see the [API document](https://tetr.io/about/api/#usersuser).
     ///
     /// # Arguments
     ///
-    /// - `social_connection` - The social connection to look up.
+    /// - `id` - The user ID to look up.
     ///
     /// # Examples
     ///
-    /// Searches for an account by Discord ID `724976600873041940`.
-    ///
     /// ```no_run
     /// use tetr_ch::prelude::*;
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    ///
-    /// // Search for an account.
-    /// let user = client.search_user(
-    ///     // By Discord ID `724976600873041940`
-    ///     SocialConnection::Discord("724976600873041940".to_string())
-    /// ).await?;
+    /// // Get the information about the user `621db46d1d638ea850be2aa0`.
+    /// let user = client.get_user_by_id("621db46d1d638ea850be2aa0").await?;
     /// # Ok(())
     /// # }
-    ///
-    /// # tokio_test::block_on(run());
     /// ```
-    pub async fn search_user(
-        &self,
-        social_connection: SocialConnection,
-    ) -> RspErr<Response<UserData>> {
-        let url = format!(
-            "{}users/search/{}",
-            API_URL,
-            encode(social_connection.to_param())
-        );
-        let res = self.client.get(url).send().await;
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::InvalidUserId`](crate::client::error::ResponseError::InvalidUserId) is returned,
+    /// if the argument `id` is not 24 lowercase hexadecimal characters.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_by_id(&self, id: &str) -> RspErr<Response<User>> {
+        if !is_valid_user_id(id) {
+            return Err(ResponseError::InvalidUserId(id.to_string()));
+        }
+        let url = build_url(&self.api_url, "users/{}", &[id]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
-    /// Gets all the summaries of the specified user.
+    /// Gets the detailed information about the specified user again,
+    /// reusing the previous response if it is still fresh.
     ///
-    /// ***Consider whether you really need to use this method.
-    /// If you only collect data for one or two game modes,
-    /// use the methods for the individual summaries instead.**
+    /// - If the cache of the previous response has not expired yet
+    ///   (based on the clock of the client, see [`Client::with_clock`]),
+    ///   the previous response is returned as is, without sending a request.
+    /// - Otherwise, a conditional request is sent with the `If-None-Match` header,
+    ///   if the previous response has an [`ETag`](crate::model::response::Response::etag).
+    ///   If the API responds with `304 Not Modified`, the previous response is returned.
     ///
-    /// About the endpoint "User Summaries",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummaries).
+    /// About the endpoint "User Info",
+    /// see the [API document](https://tetr.io/about/api/#usersuser).
     ///
     /// # Arguments
     ///
     /// - `user` - The username or user ID to look up.
+    /// - `previous` - The previous response for the user.
     ///
     /// # Examples
     ///
@@ -240,25 +426,53 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get all the summaries of the user "RINRIN-RS".
-    /// let user = client.get_user_all_summaries("rinrin-rs").await?;
+    /// let mut user = client.get_user("rinrin-rs").await?;
+    /// // Later, refresh the information only if needed.
+    /// user = client.revalidate_user("rinrin-rs", user).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_all_summaries(&self, user: &str) -> RspErr<Response<AllSummaries>> {
-        let url = format!("{}users/{}/summaries", API_URL, encode(user.to_lowercase()));
-        let res = self.client.get(url).send().await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, previous))
+    )]
+    pub async fn revalidate_user(
+        &self,
+        user: &str,
+        previous: Response<User>,
+    ) -> RspErr<Response<User>> {
+        if previous
+            .cache
+            .as_ref()
+            .is_some_and(|c| !c.is_expired_with(&*self.clock))
+        {
+            return Ok(previous);
+        }
+        let url = build_url(&self.api_url, "users/{}", &[&user.to_lowercase()]);
+        let mut request = self.client.get(url);
+        if let Some(etag) = &previous.etag {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        let res = self.send(request).await;
+        if res
+            .as_ref()
+            .is_ok_and(|r| r.status() == reqwest::StatusCode::NOT_MODIFIED)
+        {
+            return Ok(previous);
+        }
         process_response(res).await
     }
 
-    /// Gets the summary of the specified user's 40 LINES games.
+    /// Gets the detailed information about the user with the specified username.
     ///
-    /// About the endpoint "User Summary: 40 LINES",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummaries40l).
+    /// The username is case-insensitive.
+    ///
+    /// About the endpoint "User Info",
+    /// see the [API document](https://tetr.io/about/api/#usersuser).
     ///
     /// # Arguments
     ///
-    /// - `user` - The username or user ID to look up.
+    /// - `name` - The username to look up.
     ///
     /// # Examples
     ///
@@ -267,29 +481,31 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the 40 LINES games of the user "RINRIN-RS".
-    /// let user = client.get_user_40l("rinrin-rs").await?;
+    /// // Get the information about the user "RINRIN-RS".
+    /// let user = client.get_user_by_name("RINRIN-RS").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_40l(&self, user: &str) -> RspErr<Response<FortyLines>> {
-        let url = format!(
-            "{}users/{}/summaries/40l",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_by_name(&self, name: &str) -> RspErr<Response<User>> {
+        let url = build_url(&self.api_url, "users/{}", &[&name.to_lowercase()]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
-    /// Gets the summary of the specified user's BLITZ games.
+    /// Resolves the specified user IDs to their usernames.
     ///
-    /// About the endpoint "User Summary: BLITZ",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummariesblitz).
+    /// The information about the users is fetched concurrently,
+    /// up to [`Client::MAX_CONCURRENT_REQUESTS`] requests at a time,
+    /// and each user ID is requested only once even if it is duplicated.
+    /// So note that this method sends as many requests as the unique user IDs.
+    ///
+    /// The usernames are not cached,
+    /// so calling this method again sends the requests again.
     ///
     /// # Arguments
     ///
-    /// - `user` - The username or user ID to look up.
+    /// - `ids` - The user IDs to resolve.
     ///
     /// # Examples
     ///
@@ -298,29 +514,45 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the BLITZ games of the user "RINRIN-RS".
-    /// let user = client.get_user_blitz("rinrin-rs").await?;
+    /// let record = client
+    ///     .search_record("621db46d1d638ea850be2aa0", RecordGamemode::League, 1680053762145)
+    ///     .await?;
+    /// if let Some(league) = record.data.and_then(|r| r.extras.league) {
+    ///     let ids: Vec<_> = league.into_keys().collect();
+    ///     // Get the usernames of all the participants.
+    ///     let usernames = client.usernames_for(&ids).await?;
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_blitz(&self, user: &str) -> RspErr<Response<Blitz>> {
-        let url = format!(
-            "{}users/{}/summaries/blitz",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
-        process_response(res).await
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if any of the requests was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn usernames_for(&self, ids: &[UserId]) -> RspErr<HashMap<UserId, String>> {
+        let unique_ids: HashSet<&UserId> = ids.iter().collect();
+        stream::iter(unique_ids)
+            .map(|id| async move {
+                let user = self.get_user(&id.to_string()).await?.into_data()?;
+                Ok::<_, ResponseError>((id.clone(), user.username))
+            })
+            .buffer_unordered(Self::MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
     }
 
-    /// Gets the summary of the specified user's QUICK PLAY games.
+    /// Compares two users side by side.
     ///
-    /// About the endpoint "User Summary: QUICK PLAY",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszenith).
+    /// This method fetches the information and the TETRA LEAGUE summary of both users concurrently.
+    /// So note that it sends four requests to the API.
     ///
     /// # Arguments
     ///
-    /// - `user` - The username or user ID to look up.
+    /// - `a` - The username or user ID of the first user.
+    /// - `b` - The username or user ID of the second user.
     ///
     /// # Examples
     ///
@@ -329,25 +561,43 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the QUICK PLAY games of the user "RINRIN-RS".
-    /// let user = client.get_user_zenith("rinrin-rs").await?;
+    /// // Compare the user "RINRIN-RS" with the user "OSK".
+    /// let comparison = client.compare_users("rinrin-rs", "osk").await?;
+    /// // Get the difference in TR.
+    /// let tr_delta = comparison.tr_delta();
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_zenith(&self, user: &str) -> RspErr<Response<Zenith>> {
-        let url = format!(
-            "{}users/{}/summaries/zenith",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
-        process_response(res).await
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if any of the requests was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn compare_users(&self, a: &str, b: &str) -> RspErr<UserComparison> {
+        let (user_a, user_b, league_a, league_b) = try_join4(
+            self.get_user(a),
+            self.get_user(b),
+            self.get_user_league(a),
+            self.get_user_league(b),
+        )
+        .await?;
+        let league = |wrap: LeagueDataWrap| match wrap {
+            LeagueDataWrap::Some(data) => Some(data),
+            _ => None,
+        };
+        Ok(UserComparison::new(
+            ComparedUser::new(user_a.into_data()?, league(league_a.into_data()?)),
+            ComparedUser::new(user_b.into_data()?, league(league_b.into_data()?)),
+        ))
     }
 
-    /// Gets the summary of the specified user's EXPERT QUICK PLAY games.
+    /// Gets the detailed information and the TETRA LEAGUE graph of the specified user.
     ///
-    /// About the endpoint "User Summary: EXPERT QUICK PLAY",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszenithex).
+    /// This method fetches the information and the Labs Leagueflow concurrently,
+    /// so it is useful to render a profile with a TR chart.
+    /// Note that it sends two requests to the API.
     ///
     /// # Arguments
     ///
@@ -360,60 +610,79 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the EXPERT QUICK PLAY games of the user "RINRIN-RS".
-    /// let user = client.get_user_zenith_ex("rinrin-rs").await?;
+    /// // Get the information and the TETRA LEAGUE graph of the user "RINRIN-RS".
+    /// let (user, leagueflow) = client.get_profile_with_league_graph("rinrin-rs").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_zenith_ex(&self, user: &str) -> RspErr<Response<Zenith>> {
-        let url = format!(
-            "{}users/{}/summaries/zenithex",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
-        process_response(res).await
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if any of the requests was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_profile_with_league_graph(
+        &self,
+        user: &str,
+    ) -> RspErr<(User, LabsLeagueflow)> {
+        let (info, leagueflow) =
+            future::try_join(self.get_user(user), self.get_labs_leagueflow(user)).await?;
+        Ok((info.into_data()?, leagueflow.into_data()?))
     }
 
-    /// Gets the summary of the specified user's TETRA LEAGUE standing.
+    /// Searches for a TETR.IO user account by the social connection.
     ///
-    /// About the endpoint "User Summary: TETRA LEAGUE",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummariesleague).
+    /// About the endpoint "User Search",
+    /// see the [API document](https://tetr.io/about/api/#userssearchquery).
     ///
     /// # Arguments
     ///
-    /// - `user` - The username or user ID to look up.
+    /// - `social_connection` - The social connection to look up.
     ///
     /// # Examples
     ///
+    /// Searches for an account by Discord ID `724976600873041940`.
+    ///
     /// ```no_run
     /// use tetr_ch::prelude::*;
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the TETRA LEAGUE standing of the user "RINRIN-RS".
-    /// let user = client.get_user_league("rinrin-rs").await?;
+    ///
+    /// // Search for an account.
+    /// let user = client.search_user(
+    ///     // By Discord ID `724976600873041940`
+    ///     SocialConnection::Discord("724976600873041940".to_string())
+    /// ).await?;
     /// # Ok(())
     /// # }
+    ///
+    /// # tokio_test::block_on(run());
     /// ```
-    pub async fn get_user_league(&self, user: &str) -> RspErr<Response<LeagueDataWrap>> {
-        let url = format!(
-            "{}users/{}/summaries/league",
-            API_URL,
-            encode(user.to_lowercase())
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn search_user(
+        &self,
+        social_connection: SocialConnection,
+    ) -> RspErr<Response<UserData>> {
+        let url = build_url(
+            &self.api_url,
+            "users/search/{}",
+            &[&social_connection.to_param()],
         );
-        let res = self.client.get(url).send().await;
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
-    /// Gets the summary of the specified user's ZEN progress.
+    /// Searches for TETR.IO user accounts by the multiple social connections.
     ///
-    /// About the endpoint "User Summary: ZEN",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszen).
+    /// The requests are sent concurrently,
+    /// and the results are returned in the same order as the `connections`.
+    /// So note that this method sends as many requests as the connections.
     ///
     /// # Arguments
     ///
-    /// - `user` - The username or user ID to look up.
+    /// - `connections` - The social connections to look up.
     ///
     /// # Examples
     ///
@@ -422,25 +691,34 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get the summary of the ZEN progress of the user "RINRIN-RS".
-    /// let user = client.get_user_zen("rinrin-rs").await?;
+    ///
+    /// // Search for the accounts.
+    /// let results = client
+    ///     .search_users(vec![
+    ///         SocialConnection::Discord("724976600873041940".to_string()),
+    ///         SocialConnection::Discord("1057592467453067264".to_string()),
+    ///     ])
+    ///     .await;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_zen(&self, user: &str) -> RspErr<Response<Zen>> {
-        let url = format!(
-            "{}users/{}/summaries/zen",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
-        process_response(res).await
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn search_users(
+        &self,
+        connections: Vec<SocialConnection>,
+    ) -> Vec<RspErr<Response<UserData>>> {
+        join_all(connections.into_iter().map(|c| self.search_user(c))).await
     }
 
-    /// Gets all the achievements of the specified user.
+    /// Gets all the summaries of the specified user.
     ///
-    /// About the endpoint "User Summary: Achievements",
-    /// see the [API document](https://tetr.io/about/api/#usersusersummariesachievements).
+    /// ***Consider whether you really need to use this method.
+    /// If you only collect data for one or two game modes,
+    /// use the methods for the individual summaries
+    /// or [`Client::get_user_summaries`] instead.**
+    ///
+    /// About the endpoint "User Summaries",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummaries).
     ///
     /// # Arguments
     ///
@@ -453,59 +731,342 @@ impl Client {
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
-    /// // Get all the achievements of the user "RINRIN-RS".
-    /// let user = client.get_user_achievements("rinrin-rs").await?;
+    /// // Get all the summaries of the user "RINRIN-RS".
+    /// let user = client.get_user_all_summaries("rinrin-rs").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_achievements(&self, user: &str) -> RspErr<Response<Vec<Achievement>>> {
-        let url = format!(
-            "{}users/{}/summaries/achievements",
-            API_URL,
-            encode(user.to_lowercase())
-        );
-        let res = self.client.get(url).send().await;
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_all_summaries(&self, user: &str) -> RspErr<Response<AllSummaries>> {
+        let url = build_url(&self.api_url, "users/{}/summaries", &[&user.to_lowercase()]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
-    /// Gets the user leaderboard fulfilling the search criteria.
-    ///
-    /// Want to paginate over this data using the [`SearchCriteria::bound`](user_leaderboard::SearchCriteria)?
-    /// Remember to pass an `X-Session-ID` header using the [`Client::with_session_id`] to ensure data consistency.  
-    /// For more details, see the example in
-    /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
+    /// Gets the specified summaries of the specified user.
     ///
-    /// About the endpoint "User Leaderboard",
-    /// see the [API document](https://tetr.io/about/api/#usersbyleaderboard).
+    /// Unlike [`Client::get_user_all_summaries`],
+    /// this method sends a request for each of the specified summaries concurrently,
+    /// so only the needed data is fetched.
+    /// Duplicated kinds are requested only once.
     ///
     /// # Arguments
     ///
-    /// - `leaderboard` - The user leaderboard type.
-    /// - `search_criteria` - The search criteria to filter users by.
+    /// - `user` - The username or user ID to look up.
+    /// - `kinds` - The kinds of the summaries to get.
     ///
     /// # Examples
     ///
-    /// Gets the TETRA LEAGUE leaderboard with the following criteria:
-    ///
-    /// - Upper bound is `[15200, 0, 0]`
-    /// - Three entries
-    /// - Filter by Japan
-    ///
     /// ```no_run
     /// use tetr_ch::prelude::*;
     ///
     /// # async fn run() -> std::io::Result<()> {
     /// let client = Client::new();
+    /// // Get the TETRA LEAGUE and BLITZ summaries of the user "RINRIN-RS".
+    /// let summaries = client
+    ///     .get_user_summaries("rinrin-rs", &[SummaryKind::League, SummaryKind::Blitz])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// let criteria = user_leaderboard::SearchCriteria::new()
-    ///     // Upper bound is `[15200, 0, 0]`
-    ///     .after([15200.,0.,0.])
-    ///     // Three entries
-    ///     .limit(3)
-    ///     // Filter by Japan
-    ///     .country("jp");
+    /// # Errors
     ///
-    /// // Get the user leaderboard.
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if any of the requests was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_summaries(
+        &self,
+        user: &str,
+        kinds: &[SummaryKind],
+    ) -> RspErr<PartialSummaries> {
+        let (forty_lines, blitz, zenith, zenith_ex, league, zen, achievements) = try_join!(
+            fetch_if(kinds, SummaryKind::FortyLines, self.get_user_40l(user)),
+            fetch_if(kinds, SummaryKind::Blitz, self.get_user_blitz(user)),
+            fetch_if(kinds, SummaryKind::Zenith, self.get_user_zenith(user)),
+            fetch_if(kinds, SummaryKind::ZenithEx, self.get_user_zenith_ex(user)),
+            fetch_if(kinds, SummaryKind::League, self.get_user_league(user)),
+            fetch_if(kinds, SummaryKind::Zen, self.get_user_zen(user)),
+            fetch_if(
+                kinds,
+                SummaryKind::Achievements,
+                self.get_user_achievements(user)
+            ),
+        )?;
+        Ok(PartialSummaries {
+            forty_lines,
+            blitz,
+            zenith,
+            zenith_ex,
+            league,
+            zen,
+            achievements,
+        })
+    }
+
+    /// Gets the summary of the specified user's 40 LINES games.
+    ///
+    /// About the endpoint "User Summary: 40 LINES",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummaries40l).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the 40 LINES games of the user "RINRIN-RS".
+    /// let user = client.get_user_40l("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_40l(&self, user: &str) -> RspErr<Response<FortyLines>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/40l",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the summary of the specified user's BLITZ games.
+    ///
+    /// About the endpoint "User Summary: BLITZ",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummariesblitz).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the BLITZ games of the user "RINRIN-RS".
+    /// let user = client.get_user_blitz("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_blitz(&self, user: &str) -> RspErr<Response<Blitz>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/blitz",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the summary of the specified user's QUICK PLAY games.
+    ///
+    /// About the endpoint "User Summary: QUICK PLAY",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszenith).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the QUICK PLAY games of the user "RINRIN-RS".
+    /// let user = client.get_user_zenith("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_zenith(&self, user: &str) -> RspErr<Response<Zenith>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/zenith",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the summary of the specified user's EXPERT QUICK PLAY games.
+    ///
+    /// About the endpoint "User Summary: EXPERT QUICK PLAY",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszenithex).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the EXPERT QUICK PLAY games of the user "RINRIN-RS".
+    /// let user = client.get_user_zenith_ex("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_zenith_ex(&self, user: &str) -> RspErr<Response<Zenith>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/zenithex",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the summary of the specified user's TETRA LEAGUE standing.
+    ///
+    /// About the endpoint "User Summary: TETRA LEAGUE",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummariesleague).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the TETRA LEAGUE standing of the user "RINRIN-RS".
+    /// let user = client.get_user_league("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_league(&self, user: &str) -> RspErr<Response<LeagueDataWrap>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/league",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the summary of the specified user's ZEN progress.
+    ///
+    /// About the endpoint "User Summary: ZEN",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummarieszen).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the summary of the ZEN progress of the user "RINRIN-RS".
+    /// let user = client.get_user_zen("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_zen(&self, user: &str) -> RspErr<Response<Zen>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/zen",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets all the achievements of the specified user.
+    ///
+    /// About the endpoint "User Summary: Achievements",
+    /// see the [API document](https://tetr.io/about/api/#usersusersummariesachievements).
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get all the achievements of the user "RINRIN-RS".
+    /// let user = client.get_user_achievements("rinrin-rs").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_achievements(&self, user: &str) -> RspErr<Response<Vec<Achievement>>> {
+        let url = build_url(
+            &self.api_url,
+            "users/{}/summaries/achievements",
+            &[&user.to_lowercase()],
+        );
+        let res = self.send(self.client.get(url)).await;
+        process_response(res).await
+    }
+
+    /// Gets the user leaderboard fulfilling the search criteria.
+    ///
+    /// Want to paginate over this data using the [`SearchCriteria::bound`](user_leaderboard::SearchCriteria)?
+    /// Remember to pass an `X-Session-ID` header using the [`Client::with_session_id`] to ensure data consistency.  
+    /// For more details, see the example in
+    /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
+    ///
+    /// About the endpoint "User Leaderboard",
+    /// see the [API document](https://tetr.io/about/api/#usersbyleaderboard).
+    ///
+    /// # Arguments
+    ///
+    /// - `leaderboard` - The user leaderboard type.
+    /// - `search_criteria` - The search criteria to filter users by.
+    ///
+    /// # Examples
+    ///
+    /// Gets the TETRA LEAGUE leaderboard with the following criteria:
+    ///
+    /// - Upper bound is `[15200, 0, 0]`
+    /// - Three entries
+    /// - Filter by Japan
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    ///
+    /// let criteria = user_leaderboard::SearchCriteria::new()
+    ///     // Upper bound is `[15200, 0, 0]`
+    ///     .after([15200.,0.,0.])
+    ///     // Three entries
+    ///     .limit(3)
+    ///     // Filter by Japan
+    ///     .country("jp");
+    ///
+    /// // Get the user leaderboard.
     /// let user = client.get_leaderboard(
     ///     UserLeaderboardType::League,
     ///     Some(criteria)
@@ -536,6 +1097,7 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_leaderboard(
         &self,
         leaderboard: LeaderboardType,
@@ -546,8 +1108,8 @@ impl Client {
             criteria.validate_limit();
             query_params = criteria.build();
         }
-        let url = format!("{}users/by/{}", API_URL, encode(leaderboard.to_param()));
-        let res = self.client.get(url).query(&query_params).send().await;
+        let url = build_url(&self.api_url, "users/by/{}", &[&leaderboard.to_param()]);
+        let res = self.send(self.client.get(url).query(&query_params)).await;
         process_response(res).await
     }
 
@@ -621,6 +1183,7 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_historical_league_leaderboard(
         &self,
         season: &str,
@@ -631,16 +1194,53 @@ impl Client {
             criteria.validate_limit();
             query_params = criteria.build();
         }
-        let url = format!(
-            "{}users/history/{}/{}",
-            API_URL,
-            LeaderboardType::League.to_param(),
-            encode(season)
+        let url = build_url(
+            &self.api_url,
+            "users/history/{}/{}",
+            &[&LeaderboardType::League.to_param(), season],
         );
-        let res = self.client.get(url).query(&query_params).send().await;
+        let res = self.send(self.client.get(url).query(&query_params)).await;
         process_response(res).await
     }
 
+    /// Gets the champion of the specified season,
+    /// the user in the first place of the season's TETRA LEAGUE leaderboard.
+    ///
+    /// This method wraps the [`Client::get_historical_league_leaderboard`] method with limit 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `season` - The season to look up. e.g. `"1"`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    ///
+    /// // Get the champion of the season 1.
+    /// let champion = client.get_season_champion("1").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if the request was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_season_champion(&self, season: &str) -> RspErr<Option<PastUserWithPrisecter>> {
+        let criteria = user_leaderboard::SearchCriteria::new().limit(1);
+        let leaderboard = self
+            .get_historical_league_leaderboard(season, Some(criteria))
+            .await?
+            .into_data()?;
+        Ok(leaderboard.champion().cloned())
+    }
+
     /// Gets the personal record leaderboard of the specified user,
     /// fulfilling the search criteria.
     ///
@@ -717,6 +1317,13 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::UnsupportedLeaderboard`](crate::client::error::ResponseError::UnsupportedLeaderboard) is returned,
+    /// if the argument `leaderboard` is not available for the argument `gamemode`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_user_records(
         &self,
         user: &str,
@@ -724,31 +1331,76 @@ impl Client {
         leaderboard: record::LeaderboardType,
         search_criteria: Option<record::SearchCriteria>,
     ) -> RspErr<Response<UserRecords>> {
+        if !leaderboard.is_available_for(&gamemode) {
+            return Err(ResponseError::UnsupportedLeaderboard(gamemode, leaderboard));
+        }
         let mut query_params = Vec::new();
         if let Some(criteria) = search_criteria {
             criteria.validate_limit();
             query_params = criteria.build();
         }
-        let url = format!(
-            "{}users/{}/records/{}/{}",
-            API_URL,
-            encode(user.to_lowercase()),
-            gamemode.to_param(),
-            leaderboard.to_param()
+        let url = build_url(
+            &self.api_url,
+            "users/{}/records/{}/{}",
+            &[
+                &user.to_lowercase(),
+                &gamemode.to_param(),
+                &leaderboard.to_param(),
+            ],
         );
-        let res = self.client.get(url).query(&query_params).send().await;
+        let res = self.send(self.client.get(url).query(&query_params)).await;
         process_response(res).await
     }
 
-    /// Gets the record leaderboard fulfilling the search criteria.
+    /// Gets the personal best record of the specified user in the specified game mode.
     ///
-    /// Want to paginate over this data using the [`SearchCriteria::bound`](record_leaderboard::SearchCriteria)?
-    /// Remember to pass an `X-Session-ID` header using the [`Client::with_session_id`] to ensure data consistency.  
-    /// For more details, see the example in
-    /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
+    /// This is the top entry of the personal top score leaderboard,
+    /// requested using the [`Client::get_user_records`] method.
+    /// If the user has no records in the game mode, `None` is returned.
     ///
-    /// About the endpoint "Records Leaderboard",
-    /// see the [API document](https://tetr.io/about/api/#recordsleaderboard).
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    /// - `gamemode` - The game mode to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    ///
+    /// // Get the 40 LINES personal best of the user "RINRIN-RS".
+    /// let pb = client.get_user_pb("rinrin-rs", RecordGamemode::FortyLines).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if the request was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_user_pb(&self, user: &str, gamemode: Gamemode) -> RspErr<Option<Record>> {
+        let criteria = record::SearchCriteria::new().limit(1);
+        let records = self
+            .get_user_records(user, gamemode, record::LeaderboardType::Top, Some(criteria))
+            .await?
+            .into_data()?;
+        Ok(records.into_records().into_iter().next())
+    }
+
+    /// Gets the record leaderboard fulfilling the search criteria.
+    ///
+    /// Want to paginate over this data using the [`SearchCriteria::bound`](record_leaderboard::SearchCriteria)?
+    /// Remember to pass an `X-Session-ID` header using the [`Client::with_session_id`] to ensure data consistency.  
+    /// For more details, see the example in
+    /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
+    ///
+    /// About the endpoint "Records Leaderboard",
+    /// see the [API document](https://tetr.io/about/api/#recordsleaderboard).
     ///
     /// # Arguments
     ///
@@ -795,6 +1447,13 @@ impl Client {
     /// # }
     /// ```
     ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::InvalidRevolutionId`](crate::client::error::ResponseError::InvalidRevolutionId) is returned,
+    /// if the Revolution ID does not match the pattern `@YYYYwWW` (e.g. `@2024w31`).
+    /// The leading `@` is prepended if missing.
+    ///
     /// # Panics
     ///
     /// Panics if the search criteria `limit` is not between 1 and 100.
@@ -821,18 +1480,22 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_records_leaderboard(
         &self,
         leaderboard: RecordsLeaderboardId,
         search_criteria: Option<record_leaderboard::SearchCriteria>,
     ) -> RspErr<Response<RecordsLeaderboard>> {
+        let leaderboard = leaderboard
+            .normalize()
+            .map_err(ResponseError::InvalidRevolutionId)?;
         let mut query_params = Vec::new();
         if let Some(criteria) = search_criteria {
             criteria.validate_limit();
             query_params = criteria.build();
         }
-        let url = format!("{}records/{}", API_URL, encode(leaderboard.to_param()));
-        let res = self.client.get(url).query(&query_params).send().await;
+        let url = build_url(&self.api_url, "records/{}", &[&leaderboard.to_param()]);
+        let res = self.send(self.client.get(url).query(&query_params)).await;
         process_response(res).await
     }
 
@@ -876,6 +1539,7 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn search_record(
         &self,
         user_id: &str,
@@ -887,11 +1551,143 @@ impl Client {
             ("gamemode", gamemode.to_param()),
             ("ts", timestamp.to_string()),
         ];
-        let url = format!("{}records/reverse", API_URL);
-        let res = self.client.get(url).query(&query_params).send().await;
+        let url = format!("{}records/reverse", self.api_url);
+        let res = self.send(self.client.get(url).query(&query_params)).await;
         process_response(res).await
     }
 
+    /// Searches for a record of the specified user with the specified date and time.
+    ///
+    /// This is the same as [`Client::search_record`],
+    /// but the timestamp is given as a [`DateTime`](chrono::DateTime).
+    /// Note that the record is searched with the exact millisecond,
+    /// so the date and time must have the millisecond precision of the record.
+    ///
+    /// About the endpoint "Record Search",
+    /// see the [API document](https://tetr.io/about/api/#recordsreverse).
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id` - The user ID to look up.
+    /// - `gamemode` - The game mode to look up.
+    /// - `dt` - The date and time of the record to find.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    ///
+    /// let dt = "2023-03-29T01:36:02.145Z".parse().unwrap();
+    /// // Get a record.
+    /// let user = client.search_record_at(
+    ///     "621db46d1d638ea850be2aa0",
+    ///     RecordGamemode::Blitz,
+    ///     dt
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn search_record_at(
+        &self,
+        user_id: &str,
+        gamemode: Gamemode,
+        dt: chrono::DateTime<chrono::Utc>,
+    ) -> RspErr<Response<Record>> {
+        self.search_record(user_id, gamemode, dt.timestamp_millis())
+            .await
+    }
+
+    /// Gets all the records of the specified user in the specified game mode,
+    /// submitted within the specified range.
+    ///
+    /// This method paginates over the most recently placed records
+    /// using the [`Client::get_user_records`] method,
+    /// and filters them by the submission time on the client side.
+    /// So note that this method may make multiple requests.
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - The username or user ID to look up.
+    /// - `gamemode` - The game mode to look up.
+    /// - `from_ts` - The UNIX timestamp (inclusive) of the start of the range.
+    /// - `to_ts` - The UNIX timestamp (inclusive) of the end of the range.
+    ///
+    /// # Examples
+    ///
+    /// Gets the BLITZ records of the user "RINRIN-RS" submitted in August 2024.
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let records = client.records_in_range(
+    ///     "rinrin-rs",
+    ///     RecordGamemode::Blitz,
+    ///     // 2024-08-01T00:00:00Z
+    ///     1722470400,
+    ///     // 2024-08-31T23:59:59Z
+    ///     1725148799,
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if any of the requests was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn records_in_range(
+        &self,
+        user: &str,
+        gamemode: Gamemode,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> RspErr<Vec<Record>> {
+        const LIMIT: u8 = 100;
+        let mut result = Vec::new();
+        let mut criteria = record::SearchCriteria::new().limit(LIMIT);
+        loop {
+            let records = self
+                .get_user_records(
+                    user,
+                    gamemode.clone(),
+                    record::LeaderboardType::Recent,
+                    Some(criteria),
+                )
+                .await?
+                .into_data()?;
+            result.extend(
+                records
+                    .submitted_between(from_ts, to_ts)
+                    .into_iter()
+                    .cloned(),
+            );
+            let Some(last) = records.entries.last() else {
+                break;
+            };
+            // The records are sorted by the submission time in descending order.
+            if records.entries.len() < LIMIT as usize || last.submitted_at() < from_ts {
+                break;
+            }
+            match &last.prisecter {
+                Some(p) => {
+                    criteria = record::SearchCriteria::new()
+                        .limit(LIMIT)
+                        .after(p.to_array())
+                }
+                None => break,
+            }
+        }
+        Ok(result)
+    }
+
     /// Gets the latest news items in any stream.
     ///
     /// About the endpoint "All Latest News",
@@ -929,14 +1725,12 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_news_all(&self, limit: u8) -> RspErr<Response<NewsItems>> {
         validate_limit(limit);
-        let url = format!("{}news/", API_URL);
+        let url = format!("{}news/", self.api_url);
         let res = self
-            .client
-            .get(url)
-            .query(&[("limit", limit.to_string())])
-            .send()
+            .send(self.client.get(url).query(&[("limit", limit.to_string())]))
             .await;
         process_response(res).await
     }
@@ -989,14 +1783,20 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, stream), fields(stream = stream.to_param()))
+    )]
     pub async fn get_news_latest<S: ToNewsStreamParam>(
         &self,
         stream: S,
         limit: u8,
     ) -> RspErr<Response<NewsItems>> {
         validate_limit(limit);
-        let url = format!("{}news/{}", API_URL, encode(stream.to_param()));
-        let res = self.client.get(url).query(&[("limit", limit)]).send().await;
+        let url = build_url(&self.api_url, "news/{}", &[&stream.to_param()]);
+        let res = self
+            .send(self.client.get(url).query(&[("limit", limit)]))
+            .await;
         process_response(res).await
     }
 
@@ -1017,9 +1817,10 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_server_stats(&self) -> RspErr<Response<ServerStats>> {
-        let url = format!("{}general/stats", API_URL);
-        let res = self.client.get(url).send().await;
+        let url = format!("{}general/stats", self.api_url);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
@@ -1040,9 +1841,10 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_server_activity(&self) -> RspErr<Response<ServerActivity>> {
-        let url = format!("{}general/activity", API_URL);
-        let res = self.client.get(url).send().await;
+        let url = format!("{}general/activity", self.api_url);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
@@ -1076,18 +1878,18 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_labs_scoreflow(
         &self,
         user: &str,
         gamemode: Gamemode,
     ) -> RspErr<Response<LabsScoreflow>> {
-        let url = format!(
-            "{}labs/scoreflow/{}/{}",
-            API_URL,
-            encode(user.to_lowercase()),
-            gamemode.to_param()
+        let url = build_url(
+            &self.api_url,
+            "labs/scoreflow/{}/{}",
+            &[&user.to_lowercase(), &gamemode.to_param()],
         );
-        let res = self.client.get(url).send().await;
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
@@ -1113,9 +1915,10 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_labs_leagueflow(&self, user: &str) -> RspErr<Response<LabsLeagueflow>> {
-        let url = format!("{}labs/leagueflow/{}", API_URL, encode(user.to_lowercase()));
-        let res = self.client.get(url).send().await;
+        let url = build_url(&self.api_url, "labs/leagueflow/{}", &[&user.to_lowercase()]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
@@ -1137,12 +1940,44 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_labs_league_ranks(&self) -> RspErr<Response<LabsLeagueRanks>> {
-        let url = format!("{}labs/league_ranks", API_URL);
-        let res = self.client.get(url).send().await;
+        let url = format!("{}labs/league_ranks", self.api_url);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
 
+    /// Gets the amount of players per TETRA LEAGUE rank.
+    ///
+    /// This method sends only one request to the endpoint "Labs League Ranks",
+    /// which already provides the amount of players in each rank.
+    /// Unranked players are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    ///
+    /// // Get the rank distribution, from the lowest rank.
+    /// let distribution = client.get_rank_distribution().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the `get_*` methods,
+    /// a [`ResponseError::ApiErr`](crate::client::error::ResponseError::ApiErr) is returned,
+    /// if the request was unsuccessful.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_rank_distribution(&self) -> RspErr<BTreeMap<Rank, u32>> {
+        let ranks = self.get_labs_league_ranks().await?.into_data()?;
+        Ok(ranks.data.distribution())
+    }
+
     /// Gets the data about the specified achievement itself, its cutoffs, and its leaderboard.
     ///
     /// About the endpoint "Achievement Info",
@@ -1165,23 +2000,139 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub async fn get_achievement_info(
         &self,
         achievement_id: &str,
     ) -> RspErr<Response<AchievementInfo>> {
-        let url = format!("{}achievements/{}", API_URL, encode(achievement_id));
-        let res = self.client.get(url).send().await;
+        let url = build_url(&self.api_url, "achievements/{}", &[achievement_id]);
+        let res = self.send(self.client.get(url)).await;
         process_response(res).await
     }
+
+    /// Gets the raw JSON of the specified endpoint.
+    ///
+    /// This is an escape hatch for the endpoints that are not supported by this wrapper yet.
+    /// The `X-Session-ID` header is sent as with the other methods.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the endpoint, relative to the API URL. (e.g. `"general/stats"`)
+    ///   It is not percent-encoded, so it can contain a query string.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the raw JSON of the server statistics.
+    /// let json = client.get_raw("general/stats").await?;
+    /// println!("{}", json["data"]["usercount"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_raw(&self, path: &str) -> RspErr<serde_json::Value> {
+        let url = format!("{}{}", self.api_url, path.trim_start_matches('/'));
+        let res = self.send(self.client.get(url)).await;
+        process_json(res).await
+    }
+
+    /// Gets the response of the specified endpoint, deserializing the data into the type `T`.
+    ///
+    /// This allows to model the endpoints that are not supported by this wrapper yet,
+    /// while the response is handled in the same way as the other methods.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the endpoint, relative to the API URL. (e.g. `"general/stats"`)
+    ///   It is not percent-encoded.
+    /// - `query` - The query parameters to send.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use serde::Deserialize;
+    /// use tetr_ch::prelude::*;
+    ///
+    /// #[derive(Clone, Debug, Deserialize)]
+    /// struct Stats {
+    ///     usercount: u64,
+    /// }
+    ///
+    /// impl AsRef<Stats> for Stats {
+    ///     fn as_ref(&self) -> &Self {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// // Get the server statistics as the user-defined struct.
+    /// let stats = client.get_typed::<Stats>("general/stats", &[]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub async fn get_typed<T>(&self, path: &str, query: &[(&str, &str)]) -> RspErr<Response<T>>
+    where
+        for<'de> T: serde::Deserialize<'de> + Clone + std::fmt::Debug + AsRef<T>,
+    {
+        let url = format!("{}{}", self.api_url, path.trim_start_matches('/'));
+        let res = self.send(self.client.get(url).query(query)).await;
+        process_response(res).await
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
+/// Builds the URL of an endpoint.
+///
+/// Each `{}` in the argument `path` is replaced with the next element of the argument `params`,
+/// percent-encoded, so user-controlled path segments can be passed as is.
+fn build_url(api_url: &str, path: &str, params: &[&str]) -> String {
+    debug_assert_eq!(path.matches("{}").count(), params.len());
+    let mut parts = path.split("{}");
+    let mut url = format!("{}{}", api_url, parts.next().unwrap_or_default());
+    for (param, part) in params.iter().zip(parts) {
+        url.push_str(&encode(param));
+        url.push_str(part);
+    }
+    url
+}
+
+pub mod config;
 pub mod error;
 pub mod param;
 mod response;
+pub mod transport;
+
+/// Awaits the request and returns its data if the `kind` is in the `kinds`.
+///
+/// Otherwise, the request is dropped without being sent.
+async fn fetch_if<T, F>(kinds: &[SummaryKind], kind: SummaryKind, request: F) -> RspErr<Option<T>>
+where
+    T: Clone + std::fmt::Debug + AsRef<T>,
+    F: Future<Output = RspErr<Response<T>>>,
+{
+    if kinds.contains(&kind) {
+        Ok(Some(request.await?.into_data()?))
+    } else {
+        Ok(None)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::clock::FixedClock;
+    use reqwest::ResponseBuilderExt;
 
     #[test]
     fn client_new_creates_default() {
@@ -1204,6 +2155,70 @@ mod tests {
         assert!(client.x_session_id.is_some());
     }
 
+    #[test]
+    fn client_reuse_session_preserves_session_id() {
+        let client = Client::from_config(ClientConfig {
+            api_url: "http://localhost:8080/api/".to_string(),
+            session_id: Some("5a54d74d-41ed-4715-718d-dbef9ab43318".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        let derived = client.reuse_session();
+        assert_eq!(
+            derived.session_id(),
+            Some("5a54d74d-41ed-4715-718d-dbef9ab43318")
+        );
+        assert_eq!(derived.api_url, client.api_url);
+    }
+
+    #[test]
+    fn client_clone_shares_underlying_client() {
+        let client = Client::new();
+        let cloned = client.clone();
+        assert!(Arc::ptr_eq(&client.transport, &cloned.transport));
+        assert_eq!(Arc::strong_count(&client.transport), 2);
+        drop(cloned);
+        assert_eq!(Arc::strong_count(&client.transport), 1);
+    }
+
+    #[test]
+    fn client_from_config_creates_client_with_deserialized_config() {
+        let config: ClientConfig = serde_json::from_str(
+            r#"{
+                "api_url": "http://localhost:8080/api/",
+                "timeout_ms": 5000,
+                "session_id": "5a54d74d-41ed-4715-718d-dbef9ab43318"
+            }"#,
+        )
+        .unwrap();
+        let client = Client::from_config(config.clone()).unwrap();
+        assert_eq!(client.api_url, config.api_url);
+        assert_eq!(client.session_id(), config.session_id.as_deref());
+    }
+
+    #[test]
+    fn client_from_config_returns_error_if_invalid_session_id() {
+        let config = ClientConfig {
+            session_id: Some("\n".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Client::from_config(config),
+            Err(ClientCreationError::InvalidHeaderValue(_))
+        ));
+    }
+
+    #[cfg(all(feature = "gzip", feature = "brotli"))]
+    #[test]
+    fn client_from_config_creates_client_with_gzip_enabled() {
+        let config = ClientConfig {
+            gzip: true,
+            brotli: false,
+            ..Default::default()
+        };
+        assert!(Client::from_config(config).is_ok());
+    }
+
     #[test]
     fn client_with_session_id_returns_error_if_invalid_session_id() {
         let invalid_session_id = "\n";
@@ -1213,4 +2228,876 @@ mod tests {
             Err(ClientCreationError::InvalidHeaderValue(_))
         ));
     }
+
+    #[tokio::test]
+    async fn client_get_user_by_id_returns_error_if_invalid_id() {
+        let result = Client::new().get_user_by_id("rinrin-rs").await;
+        assert!(matches!(result, Err(ResponseError::InvalidUserId(id)) if id == "rinrin-rs"));
+    }
+
+    #[tokio::test]
+    async fn client_search_record_at_sends_timestamp_in_milliseconds() {
+        let (client, transport) = test_client(|_| success(serde_json::Value::Null));
+        let dt = "2023-03-29T01:36:02.145Z".parse().unwrap();
+        let rsp = client
+            .search_record_at("621db46d1d638ea850be2aa0", Gamemode::Blitz, dt)
+            .await
+            .unwrap();
+        assert!(!rsp.found());
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            ["/api/records/reverse?user=621db46d1d638ea850be2aa0&gamemode=blitz&ts=1680053762145"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_get_records_leaderboard_returns_error_if_invalid_revolution_id() {
+        let leaderboard =
+            RecordsLeaderboardId::new("zenith", record_leaderboard::Scope::Global, Some("garbage"));
+        let result = Client::new()
+            .get_records_leaderboard(leaderboard, None)
+            .await;
+        assert!(matches!(result, Err(ResponseError::InvalidRevolutionId(id)) if id == "garbage"));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn client_emits_tracing_events_per_request() {
+        use std::{
+            fmt::Debug,
+            sync::{
+                atomic::{AtomicU64, Ordering},
+                Mutex,
+            },
+        };
+        use tracing::{
+            field::{Field, Visit},
+            span, subscriber, Event, Metadata, Subscriber,
+        };
+
+        /// Formats the recorded fields as `name=value`.
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        /// Records the spans and the events of this crate as strings.
+        #[derive(Default)]
+        struct Recorder {
+            next_id: AtomicU64,
+            records: Arc<Mutex<Vec<String>>>,
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target().starts_with("tetr_ch")
+            }
+            fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+                let mut fields = Fields(Vec::new());
+                attrs.record(&mut fields);
+                self.records.lock().unwrap().push(format!(
+                    "span {} {}",
+                    attrs.metadata().name(),
+                    fields.0.join(" ")
+                ));
+                span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+            }
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                self.records.lock().unwrap().push(fields.0.join(" "));
+            }
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let (client, _) = test_client(|_| success(user_json("rinrin-rs")));
+        let recorder = Recorder::default();
+        let records = recorder.records.clone();
+        let _guard = subscriber::set_default(recorder);
+        client.get_user("RINRIN-RS").await.unwrap();
+        assert_eq!(
+            *records.lock().unwrap(),
+            [
+                "span get_user user=\"RINRIN-RS\"".to_string(),
+                "message=received a response url=https://ch.tetr.io/api/users/rinrin-rs status=200"
+                    .to_string(),
+                "message=cache status cache=hit".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_usernames_for_returns_empty_map_if_no_ids() {
+        let usernames = Client::new().usernames_for(&[]).await.unwrap();
+        assert!(usernames.is_empty());
+    }
+
+    #[tokio::test]
+    async fn client_usernames_for_requests_each_unique_id_once() {
+        let (client, transport) = test_client(|path| {
+            let id = path.rsplit('/').next().unwrap();
+            success(user_json(&format!("user-{}", &id[20..])))
+        });
+        let a: UserId = "621db46d1d638ea850be2aa0".parse().unwrap();
+        let b: UserId = "5e32fc85ab319c2ab1beb07c".parse().unwrap();
+        let usernames = client
+            .usernames_for(&[a.clone(), b.clone(), a.clone(), b.clone(), a.clone()])
+            .await
+            .unwrap();
+        assert_eq!(
+            usernames,
+            HashMap::from([(a, "user-2aa0".to_string()), (b, "user-b07c".to_string())])
+        );
+        let mut paths = transport.paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/api/users/5e32fc85ab319c2ab1beb07c",
+                "/api/users/621db46d1d638ea850be2aa0"
+            ]
+        );
+    }
+
+    /// A transport that responds with the JSON returned by the responder,
+    /// without hitting the network.
+    struct MockTransport {
+        /// Returns the response body for the requested path (with the query string).
+        responder: fn(&str) -> serde_json::Value,
+        /// The requested paths (with the query string).
+        paths: std::sync::Mutex<Vec<String>>,
+        /// The `X-Session-ID` headers of the requests.
+        session_ids: std::sync::Mutex<Vec<Option<String>>>,
+    }
+
+    impl Transport for MockTransport {
+        fn send(
+            &self,
+            request: reqwest::Request,
+        ) -> future::BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+            let url = request.url();
+            let path = match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            };
+            let body = (self.responder)(&path).to_string();
+            self.paths.lock().unwrap().push(path);
+            self.session_ids.lock().unwrap().push(
+                request
+                    .headers()
+                    .get("X-Session-ID")
+                    .map(|v| v.to_str().unwrap().to_string()),
+            );
+            let rsp = http::Response::builder()
+                .status(200)
+                .header(header::CONTENT_TYPE, "application/json")
+                .url(url.clone())
+                .body(body)
+                .unwrap();
+            Box::pin(future::ready(Ok(rsp.into())))
+        }
+    }
+
+    /// Creates a client that sends the requests to a [`MockTransport`] with the responder.
+    ///
+    /// Returns the client and the transport to inspect the requests.
+    fn test_client(responder: fn(&str) -> serde_json::Value) -> (Client, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport {
+            responder,
+            paths: Default::default(),
+            session_ids: Default::default(),
+        });
+        (Client::new().with_transport(transport.clone()), transport)
+    }
+
+    /// Returns a successful response body with the data.
+    fn success(data: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "success": true,
+            "cache": { "status": "hit", "cached_at": 0, "cached_until": 0 },
+            "data": data
+        })
+    }
+
+    /// Returns the data of the endpoint "User Info" for the username.
+    fn user_json(username: &str) -> serde_json::Value {
+        serde_json::json!({
+            "_id": "621db46d1d638ea850be2aa0",
+            "username": username,
+            "role": "user",
+            "badges": [],
+            "xp": 0.0,
+            "gamesplayed": 0,
+            "gameswon": 0,
+            "gametime": 0.0,
+            "supporter_tier": 0,
+            "connections": {},
+            "achievements": [],
+            "ar": 0,
+            "ar_counts": {}
+        })
+    }
+
+    #[tokio::test]
+    async fn client_get_raw_returns_json_of_any_endpoint() {
+        let (client, transport) =
+            test_client(|path| success(serde_json::json!({ "path": path, "items": [1, 2, 3] })));
+        let json = client.get_raw("/labs/new_endpoint?x=1").await.unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["data"]["path"], "/api/labs/new_endpoint?x=1");
+        assert_eq!(json["data"]["items"][2], 3);
+        client.get_raw("labs/new_endpoint").await.unwrap();
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            ["/api/labs/new_endpoint?x=1", "/api/labs/new_endpoint"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_get_typed_deserializes_into_user_defined_type() {
+        #[derive(Clone, Debug, serde::Deserialize)]
+        struct Item {
+            path: String,
+            count: u32,
+        }
+
+        impl AsRef<Item> for Item {
+            fn as_ref(&self) -> &Self {
+                self
+            }
+        }
+
+        let (client, transport) =
+            test_client(|path| success(serde_json::json!({ "path": path, "count": 42 })));
+        let item = client
+            .get_typed::<Item>("labs/new_endpoint", &[("limit", "5"), ("q", "a b")])
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(item.count, 42);
+        assert_eq!(item.path, "/api/labs/new_endpoint?limit=5&q=a+b");
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            ["/api/labs/new_endpoint?limit=5&q=a+b"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_get_season_champion_returns_first_place() {
+        let (client, transport) = test_client(|path| {
+            success(if path.starts_with("/api/users/history/league/1?") {
+                serde_json::json!({ "entries": [{
+                    "_id": "5e4979d4fad3ca55f6512458",
+                    "season": "1",
+                    "username": "czsmall0402",
+                    "country": "CN",
+                    "placement": 1,
+                    "ranked": true,
+                    "gamesplayed": 1127,
+                    "gameswon": 871,
+                    "glicko": 4034.38,
+                    "rd": 60.27,
+                    "tr": 24946.72,
+                    "gxe": 99.63,
+                    "rank": "x+",
+                    "bestrank": "x+",
+                    "apm": 179.34,
+                    "pps": 3.23,
+                    "vs": 368.92,
+                    "p": { "pri": 24946.72, "sec": 0.0, "ter": 0.0 }
+                }] })
+            } else {
+                serde_json::json!({ "entries": [] })
+            })
+        });
+        let champion = client.get_season_champion("1").await.unwrap().unwrap();
+        assert_eq!(champion.placement, 1);
+        assert_eq!(champion.username, "czsmall0402");
+        assert!(client.get_season_champion("2").await.unwrap().is_none());
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            [
+                "/api/users/history/league/1?limit=1",
+                "/api/users/history/league/2?limit=1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_get_user_pb_returns_top_record() {
+        let (client, transport) = test_client(|path| {
+            success(if path.contains("/rinrin-rs/") {
+                serde_json::json!({ "entries": [{
+                    "_id": "6575b4a2dc1b3a8ef2e6a2a0",
+                    "replayid": "6575b4a2dc1b3a8ef2e6a2a0",
+                    "stub": false,
+                    "gamemode": "40l",
+                    "pb": true,
+                    "oncepb": true,
+                    "ts": "2023-12-10T12:58:10.123Z",
+                    "otherusers": [],
+                    "leaderboards": ["40l_global"],
+                    "disputed": false,
+                    "results": {},
+                    "extras": {}
+                }] })
+            } else {
+                serde_json::json!({ "entries": [] })
+            })
+        });
+        let pb = client
+            .get_user_pb("RINRIN-RS", Gamemode::FortyLines)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(pb.is_personal_best);
+        assert!(client
+            .get_user_pb("nobody", Gamemode::Blitz)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            [
+                "/api/users/rinrin-rs/records/40l/top?limit=1",
+                "/api/users/nobody/records/blitz/top?limit=1"
+            ]
+        );
+    }
+
+    /// 2024-08-31T23:59:59Z
+    const RECENT_BASE_TS: i64 = 1725148799;
+
+    /// Recent records submitted an hour apart, newest first.
+    fn recent_records(range: std::ops::Range<i64>) -> serde_json::Value {
+        let entries: Vec<_> = range
+            .map(|i| {
+                let ts = chrono::DateTime::from_timestamp(RECENT_BASE_TS - i * 3600, 0).unwrap();
+                serde_json::json!({
+                    "_id": format!("{i:024x}"),
+                    "replayid": format!("{i:024x}"),
+                    "stub": false,
+                    "gamemode": "blitz",
+                    "pb": false,
+                    "oncepb": false,
+                    "ts": ts.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                    "otherusers": [],
+                    "leaderboards": [],
+                    "disputed": false,
+                    "results": {},
+                    "extras": {},
+                    "p": { "pri": i, "sec": 0.0, "ter": 0.0 }
+                })
+            })
+            .collect();
+        serde_json::json!({ "entries": entries })
+    }
+
+    #[tokio::test]
+    async fn client_records_in_range_paginates_until_from_ts() {
+        let (client, transport) = test_client(|path| {
+            success(if path.contains("after=") {
+                // Crosses `from_ts` at the 150th record.
+                recent_records(100..200)
+            } else {
+                recent_records(0..100)
+            })
+        });
+        let records = client
+            .records_in_range(
+                "rinrin-rs",
+                Gamemode::Blitz,
+                RECENT_BASE_TS - 150 * 3600,
+                RECENT_BASE_TS - 5 * 3600,
+            )
+            .await
+            .unwrap();
+        let ids: Vec<_> = records.iter().map(|r| r.id.clone()).collect();
+        let expected: Vec<_> = (5..=150).map(|i| format!("{i:024x}")).collect();
+        assert_eq!(ids, expected);
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            [
+                "/api/users/rinrin-rs/records/blitz/recent?limit=100",
+                "/api/users/rinrin-rs/records/blitz/recent?after=99%3A0%3A0&limit=100"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_records_in_range_stops_without_prisecter() {
+        let (client, transport) = test_client(|_| {
+            let mut records = recent_records(0..100);
+            for entry in records["entries"].as_array_mut().unwrap() {
+                entry.as_object_mut().unwrap().remove("p");
+            }
+            success(records)
+        });
+        let records = client
+            .records_in_range(
+                "rinrin-rs",
+                Gamemode::Blitz,
+                RECENT_BASE_TS - 500 * 3600,
+                RECENT_BASE_TS,
+            )
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 100);
+        assert_eq!(transport.paths.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn client_get_rank_distribution_counts_players_per_rank() {
+        let (client, transport) = test_client(|_| {
+            let ranks = [
+                "x+", "x", "u", "ss", "s+", "s", "s-", "a+", "a", "a-", "b+", "b", "b-", "c+", "c",
+                "c-", "d+", "d",
+            ];
+            let mut data = serde_json::Map::new();
+            data.insert("total".to_string(), serde_json::json!(171));
+            for (i, rank) in ranks.into_iter().enumerate() {
+                data.insert(
+                    rank.to_string(),
+                    serde_json::json!({
+                        "pos": i,
+                        "percentile": 0.0,
+                        "tr": 25000 - i * 1000,
+                        "targettr": 0.0,
+                        "apm": null,
+                        "pps": null,
+                        "vs": null,
+                        "count": i + 1
+                    }),
+                );
+            }
+            success(serde_json::json!({
+                "_id": "league_ranks_1724457600000",
+                "s": "league_ranks",
+                "t": "2024-08-24T00:00:00.000Z",
+                "data": data
+            }))
+        });
+        let distribution = client.get_rank_distribution().await.unwrap();
+        assert_eq!(distribution.len(), 18);
+        assert_eq!(distribution[&Rank::XPlus], 1);
+        assert_eq!(distribution[&Rank::D], 18);
+        assert!(!distribution.contains_key(&Rank::Z));
+        assert_eq!(*transport.paths.lock().unwrap(), ["/api/labs/league_ranks"]);
+    }
+
+    #[tokio::test]
+    async fn client_get_rank_distribution_returns_api_error() {
+        let (client, _) = test_client(
+            |_| serde_json::json!({ "success": false, "error": { "msg": "Labs are unavailable." } }),
+        );
+        let err = client.get_rank_distribution().await.unwrap_err();
+        assert!(matches!(
+            err,
+            ResponseError::ApiErr(e) if e.msg.as_deref() == Some("Labs are unavailable.")
+        ));
+    }
+
+    #[tokio::test]
+    async fn client_get_user_records_rejects_unsupported_leaderboard() {
+        let (client, transport) = test_client(|_| success(serde_json::json!({ "entries": [] })));
+        let result = client
+            .get_user_records(
+                "rinrin-rs",
+                Gamemode::League,
+                record::LeaderboardType::Progression,
+                None,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(ResponseError::UnsupportedLeaderboard(
+                Gamemode::League,
+                record::LeaderboardType::Progression
+            ))
+        ));
+        assert!(transport.paths.lock().unwrap().is_empty());
+        let records = client
+            .get_user_records(
+                "rinrin-rs",
+                Gamemode::League,
+                record::LeaderboardType::Recent,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(records.is_success);
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            ["/api/users/rinrin-rs/records/league/recent"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_get_user_summaries_requests_only_specified_kinds() {
+        let (client, transport) = test_client(|path| {
+            success(if path.ends_with("/league") {
+                serde_json::json!({
+                    "gamesplayed": 0,
+                    "gameswon": 0,
+                    "glicko": -1,
+                    "rd": null,
+                    "decaying": false,
+                    "tr": -1,
+                    "gxe": -1,
+                    "rank": "z",
+                    "past": {}
+                })
+            } else {
+                serde_json::json!({ "record": null, "rank": -1, "rank_local": -1 })
+            })
+        });
+        let summaries = client
+            .get_user_summaries(
+                "RINRIN",
+                &[SummaryKind::League, SummaryKind::Blitz, SummaryKind::League],
+            )
+            .await
+            .unwrap();
+        assert!(summaries.league.unwrap().is_some());
+        assert_eq!(summaries.blitz.unwrap().rank, -1);
+        assert!(summaries.forty_lines.is_none());
+        assert!(summaries.zenith.is_none());
+        assert!(summaries.zenith_ex.is_none());
+        assert!(summaries.zen.is_none());
+        assert!(summaries.achievements.is_none());
+        let mut paths = transport.paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/api/users/rinrin/summaries/blitz",
+                "/api/users/rinrin/summaries/league"
+            ]
+        );
+    }
+
+    /// Responds to the requests of [`Client::compare_users`].
+    ///
+    /// The users `alice` and `bob` exist, and the other users do not.
+    fn compare_users_responder(path: &str) -> serde_json::Value {
+        let (xp, tr, gxe, apm, pps, vs) = if path.contains("/alice") {
+            (32768., 15000., 70., 60., 2.5, 130.)
+        } else if path.contains("/bob") {
+            (4096., 12000., 55., 45., 2., 100.)
+        } else {
+            return serde_json::json!({ "success": false, "error": { "msg": "No such user!" } });
+        };
+        if path.ends_with("/summaries/league") {
+            success(serde_json::json!({
+                "gamesplayed": 20,
+                "gameswon": 10,
+                "glicko": 1500.0,
+                "rd": 60.0,
+                "decaying": false,
+                "tr": tr,
+                "gxe": gxe,
+                "rank": "a",
+                "apm": apm,
+                "pps": pps,
+                "vs": vs,
+                "past": {}
+            }))
+        } else {
+            let mut user = user_json(path.rsplit('/').next().unwrap());
+            user["xp"] = serde_json::json!(xp);
+            success(user)
+        }
+    }
+
+    #[tokio::test]
+    async fn client_compare_users_computes_deltas() {
+        let (client, transport) = test_client(compare_users_responder);
+        let comparison = client.compare_users("Alice", "bob").await.unwrap();
+        assert_eq!(comparison.a.user.username, "alice");
+        assert_eq!(comparison.b.user.username, "bob");
+        assert_eq!(comparison.xp_delta(), 32768. - 4096.);
+        assert_eq!(comparison.level_delta(), 19 - 5);
+        assert_eq!(comparison.tr_delta(), Some(3000.));
+        assert_eq!(comparison.apm_delta(), Some(15.));
+        assert_eq!(comparison.pps_delta(), Some(0.5));
+        assert_eq!(comparison.vs_delta(), Some(30.));
+        let mut paths = transport.paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/api/users/alice",
+                "/api/users/alice/summaries/league",
+                "/api/users/bob",
+                "/api/users/bob/summaries/league"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_compare_users_returns_api_error_if_user_is_missing() {
+        let (client, _) = test_client(compare_users_responder);
+        let err = client.compare_users("alice", "nobody").await.unwrap_err();
+        assert!(matches!(
+            err,
+            ResponseError::ApiErr(e) if e.msg.as_deref() == Some("No such user!")
+        ));
+    }
+
+    #[tokio::test]
+    async fn client_get_profile_with_league_graph_returns_both() {
+        let (client, transport) = test_client(|path| {
+            success(if path.starts_with("/api/labs/leagueflow/") {
+                serde_json::json!({
+                    "startTime": 1700000000000i64,
+                    "points": [[0, 1, 10000, 9000], [60000, 2, 9950, 11000]]
+                })
+            } else {
+                user_json("rinrin-rs")
+            })
+        });
+        let (user, leagueflow) = client
+            .get_profile_with_league_graph("rinrin-rs")
+            .await
+            .unwrap();
+        assert_eq!(user.username, "rinrin-rs");
+        assert_eq!(leagueflow.points.len(), 2);
+        let mut paths = transport.paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            ["/api/labs/leagueflow/rinrin-rs", "/api/users/rinrin-rs"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_search_users_preserves_order() {
+        let (client, transport) = test_client(|path| {
+            let id = path.rsplit("%3A").next().unwrap();
+            success(serde_json::json!({
+                "user": { "_id": "621db46d1d638ea850be2aa0", "username": format!("user-{}", id) }
+            }))
+        });
+        let results = client
+            .search_users(vec![
+                SocialConnection::Discord("724976600873041940".to_string()),
+                SocialConnection::Twitch("12345".to_string()),
+            ])
+            .await;
+        let usernames: Vec<String> = results
+            .into_iter()
+            .map(|r| r.unwrap().into_data().unwrap().user.unwrap().username)
+            .collect();
+        assert_eq!(usernames, ["user-724976600873041940", "user-12345"]);
+        let mut paths = transport.paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/api/users/search/discord%3A724976600873041940",
+                "/api/users/search/twitch%3A12345"
+            ]
+        );
+    }
+
+    #[test]
+    fn build_url_encodes_params() {
+        assert_eq!(
+            build_url(API_URL, "users/{}", &["rinrin-rs"]),
+            "https://ch.tetr.io/api/users/rinrin-rs"
+        );
+        assert_eq!(
+            build_url(API_URL, "users/{}/summaries/40l", &["a b/c"]),
+            "https://ch.tetr.io/api/users/a%20b%2Fc/summaries/40l"
+        );
+        assert_eq!(
+            build_url(API_URL, "labs/scoreflow/{}/{}", &["../x?y#z", "40l"]),
+            "https://ch.tetr.io/api/labs/scoreflow/%20%20%2Fx%3Fy%23z/40l"
+        );
+    }
+
+    #[test]
+    fn build_url_encodes_unicode_params() {
+        assert_eq!(
+            build_url(API_URL, "users/{}", &["りんりん"]),
+            "https://ch.tetr.io/api/users/%E3%82%8A%E3%82%93%E3%82%8A%E3%82%93"
+        );
+    }
+
+    #[test]
+    fn build_url_keeps_path_without_params() {
+        assert_eq!(
+            build_url(API_URL, "general/stats", &[]),
+            "https://ch.tetr.io/api/general/stats"
+        );
+    }
+
+    #[tokio::test]
+    async fn client_returns_deserialize_error_with_body_excerpt() {
+        let (client, _) = test_client(
+            |_| serde_json::json!({ "success": true, "data": { "unexpected": "format" } }),
+        );
+        let err = client.get_server_stats().await.unwrap_err();
+        assert!(
+            matches!(&err, ResponseError::DeserializeErr(_, body) if body.contains("unexpected"))
+        );
+        assert!(err
+            .to_string()
+            .ends_with(r#"(response body: `{"data":{"unexpected":"format"},"success":true}`)"#));
+    }
+
+    #[tokio::test]
+    async fn client_returns_unexpected_content_type_error_if_html() {
+        struct HtmlTransport;
+        impl Transport for HtmlTransport {
+            fn send(
+                &self,
+                _: reqwest::Request,
+            ) -> future::BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+                let rsp = http::Response::builder()
+                    .status(503)
+                    .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+                    .body("<html><body>Under maintenance</body></html>")
+                    .unwrap();
+                Box::pin(future::ready(Ok(rsp.into())))
+            }
+        }
+        let client = Client::new().with_transport(HtmlTransport);
+        let err = client.get_server_stats().await.unwrap_err();
+        assert!(matches!(
+            err,
+            ResponseError::UnexpectedContentType { content_type, status }
+                if content_type == "text/html; charset=utf-8"
+                    && status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+    }
+
+    #[tokio::test]
+    async fn client_with_transport_sends_requests_with_the_transport() {
+        let (client, transport) = test_client(
+            |_| serde_json::json!({ "success": false, "error": { "msg": "No such user!" } }),
+        );
+        let rsp = client.get_user("RINRIN-RS").await.unwrap();
+        assert!(!rsp.is_success);
+        assert_eq!(rsp.error.unwrap().msg.as_deref(), Some("No such user!"));
+        let derived = client.reuse_session();
+        derived.get_raw("general/stats").await.unwrap();
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            ["/api/users/rinrin-rs", "/api/general/stats"]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_with_transport_sends_session_id() {
+        let (client, transport) = test_client(|_| success(user_json("rinrin-rs")));
+        client.get_user("rinrin-rs").await.unwrap();
+        let session = Client::with_session_id(Some("session"))
+            .unwrap()
+            .with_transport(transport.clone());
+        session.get_user("rinrin-rs").await.unwrap();
+        assert_eq!(
+            *transport.session_ids.lock().unwrap(),
+            [None, Some("session".to_string())]
+        );
+    }
+
+    /// A transport that responds with `304 Not Modified` to the conditional requests.
+    #[derive(Default)]
+    struct EtagTransport {
+        if_none_match: std::sync::Mutex<Vec<Option<String>>>,
+    }
+
+    impl Transport for EtagTransport {
+        fn send(
+            &self,
+            request: reqwest::Request,
+        ) -> future::BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+            let tag = request
+                .headers()
+                .get(header::IF_NONE_MATCH)
+                .map(|v| v.to_str().unwrap().to_string());
+            let rsp = if tag.is_some() {
+                http::Response::builder()
+                    .status(304)
+                    .body(String::new())
+                    .unwrap()
+            } else {
+                let body = serde_json::json!({
+                    "success": true,
+                    "cache": {
+                        "status": "miss",
+                        "cached_at": 1661710769000u64,
+                        "cached_until": 1661710844000u64
+                    },
+                    "data": {
+                        "_id": "621db46d1d638ea850be2aa0",
+                        "username": "rinrin-rs",
+                        "role": "user",
+                        "badges": [],
+                        "xp": 0.0,
+                        "gamesplayed": 0,
+                        "gameswon": 0,
+                        "gametime": 0.0,
+                        "supporter_tier": 0,
+                        "connections": {},
+                        "achievements": [],
+                        "ar": 0,
+                        "ar_counts": {}
+                    }
+                });
+                http::Response::builder()
+                    .status(200)
+                    .header(header::ETAG, "\"v1\"")
+                    .body(body.to_string())
+                    .unwrap()
+            };
+            self.if_none_match.lock().unwrap().push(tag);
+            Box::pin(future::ready(Ok(rsp.into())))
+        }
+    }
+
+    #[tokio::test]
+    async fn client_revalidate_user_returns_previous_response_if_not_modified() {
+        let transport = Arc::new(EtagTransport::default());
+        // The cache expires at 1661710844000.
+        let client = Client::new()
+            .with_transport(transport.clone())
+            .with_clock(FixedClock(1661710844000));
+        let previous = client.get_user("rinrin-rs").await.unwrap();
+        assert_eq!(previous.etag.as_deref(), Some("\"v1\""));
+        let revalidated = client
+            .revalidate_user("rinrin-rs", previous.clone())
+            .await
+            .unwrap();
+        assert_eq!(revalidated.etag, previous.etag);
+        assert_eq!(revalidated.cache, previous.cache);
+        assert_eq!(
+            *transport.if_none_match.lock().unwrap(),
+            [None, Some("\"v1\"".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_revalidate_user_skips_request_if_cache_is_fresh() {
+        let transport = Arc::new(EtagTransport::default());
+        let client = Client::new()
+            .with_transport(transport.clone())
+            .with_clock(FixedClock(1661710769000));
+        let previous = client.get_user("rinrin-rs").await.unwrap();
+        let revalidated = client
+            .revalidate_user("rinrin-rs", previous.clone())
+            .await
+            .unwrap();
+        assert_eq!(revalidated.etag, previous.etag);
+        assert_eq!(revalidated.cache, previous.cache);
+        assert_eq!(transport.if_none_match.lock().unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,118 @@
+//! A configuration for the [`Client`](crate::client::Client).
+
+use super::API_URL;
+use serde::{Deserialize, Serialize};
+
+/// A configuration for the [`Client`](crate::client::Client).
+///
+/// This can be serialized and deserialized,
+/// so it is possible to load the client settings from a config file.
+///
+/// # Examples
+///
+/// ```
+/// use tetr_ch::{client::config::ClientConfig, prelude::*};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config: ClientConfig = serde_json::from_str(r#"{ "timeout_ms": 10000 }"#)?;
+/// let client = Client::from_config(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// The base URL of the API.
+    /// `https://ch.tetr.io/api/` by default.
+    pub api_url: String,
+    /// The timeout of each request in milliseconds.
+    /// No timeout by default.
+    pub timeout_ms: Option<u64>,
+    /// The session ID to set in the `X-Session-ID` header.
+    /// No header by default.
+    pub session_id: Option<String>,
+    /// Whether to request and decompress gzip-compressed responses.
+    /// Enabled by default.
+    ///
+    /// Available on the `gzip` feature only.
+    #[cfg(feature = "gzip")]
+    pub gzip: bool,
+    /// Whether to request and decompress brotli-compressed responses.
+    /// Enabled by default.
+    ///
+    /// Available on the `brotli` feature only.
+    #[cfg(feature = "brotli")]
+    pub brotli: bool,
+}
+
+impl ClientConfig {
+    /// Creates a new [`ClientConfig`].
+    /// The values are set to default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::client::config::ClientConfig;
+    /// let config = ClientConfig::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            api_url: API_URL.to_string(),
+            timeout_ms: None,
+            session_id: None,
+            #[cfg(feature = "gzip")]
+            gzip: true,
+            #[cfg(feature = "brotli")]
+            brotli: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn client_config_new_creates_default() {
+        let config = ClientConfig::new();
+        assert_eq!(config.api_url, "https://ch.tetr.io/api/");
+        assert!(config.timeout_ms.is_none());
+        assert!(config.session_id.is_none());
+    }
+
+    #[cfg(all(feature = "gzip", feature = "brotli"))]
+    #[test]
+    fn client_config_new_enables_compression() {
+        let config = ClientConfig::new();
+        assert!(config.gzip);
+        assert!(config.brotli);
+    }
+
+    #[test]
+    fn client_config_deserializes_missing_fields_to_default() {
+        let config: ClientConfig = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(config, ClientConfig::default());
+    }
+
+    #[test]
+    fn client_config_round_trips() {
+        let mut config = ClientConfig::new();
+        config.api_url = "http://localhost:8080/api/".to_string();
+        config.timeout_ms = Some(5000);
+        config.session_id = Some("5a54d74d-41ed-4715-718d-dbef9ab43318".to_string());
+        #[cfg(feature = "brotli")]
+        {
+            config.brotli = false;
+        }
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized: ClientConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, config);
+    }
+}
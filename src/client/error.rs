@@ -1,33 +1,98 @@
 //! A module for the error related types for the [`client`](crate::client) module.
 
+use crate::{
+    client::param::record::{Gamemode, LeaderboardType},
+    model::response::ErrorResponse,
+};
 use http::status::StatusCode;
 use std::fmt;
 
 /// An enum for the response handling errors.
+///
+/// New variants may be added in the future,
+/// so a `match` on this enum needs a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ResponseError {
     /// The request failed.
     RequestErr(reqwest::Error),
     /// The response did not match the expected format but the HTTP request succeeded.
     ///
     /// There may be defectives in this wrapper or the TETRA CHANNEL API document.
-    DeserializeErr(reqwest::Error),
+    /// Contains the deserialization error and the beginning of the response body.
+    DeserializeErr(serde_json::Error, String),
     /// The HTTP request failed and the response did not match the expected format.
     ///
     /// Even if the HTTP status code is not within 200-299.
     /// it may be possible to deserialize the response containing an error message,
     /// so the deserialization will be tried before returning this error.
     HttpErr(StatusCode),
+    /// The given user ID is malformed.
+    ///
+    /// A user ID must be 24 lowercase hexadecimal characters.
+    /// This error is returned before the request is sent.
+    InvalidUserId(String),
+    /// The given Revolution ID is malformed.
+    ///
+    /// A Revolution ID must match the pattern `@YYYYwWW` (e.g. `@2024w31`).
+    /// The leading `@` can be omitted.
+    /// This error is returned before the request is sent.
+    InvalidRevolutionId(String),
+    /// The given personal leaderboard is not available for the given game mode.
+    ///
+    /// See [`LeaderboardType::is_available_for`] for the available combinations.
+    /// This error is returned before the request is sent.
+    UnsupportedLeaderboard(Gamemode, LeaderboardType),
+    /// The response was not JSON (e.g. an HTML error page during maintenance).
+    ///
+    /// This is returned if the `Content-Type` header of the response is not JSON.
+    UnexpectedContentType {
+        /// The `Content-Type` header of the response.
+        content_type: String,
+        /// The HTTP status code of the response.
+        status: StatusCode,
+    },
+    /// The TETRA CHANNEL API returned an error instead of the requested data.
+    ///
+    /// This is only returned by the methods that combine multiple requests,
+    /// since the other methods return the error in the [`Response`](crate::model::response::Response) as is.
+    ApiErr(ErrorResponse),
 }
 
-impl std::error::Error for ResponseError {}
+impl std::error::Error for ResponseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResponseError::RequestErr(err) => Some(err),
+            ResponseError::DeserializeErr(err, _) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for ResponseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ResponseError::DeserializeErr(msg) => write!(f, "{}", msg),
+            ResponseError::DeserializeErr(err, body) => {
+                write!(f, "{} (response body: `{}`)", err, body)
+            }
             ResponseError::RequestErr(err) => write!(f, "{}", err),
             ResponseError::HttpErr(status) => write!(f, "{}", status),
+            ResponseError::InvalidUserId(id) => write!(f, "invalid user ID `{}`", id),
+            ResponseError::InvalidRevolutionId(id) => write!(f, "invalid Revolution ID `{}`", id),
+            ResponseError::UnsupportedLeaderboard(gamemode, leaderboard) => write!(
+                f,
+                "the personal leaderboard `{}` is not available for the game mode `{}`",
+                leaderboard.to_param(),
+                gamemode.to_param()
+            ),
+            ResponseError::UnexpectedContentType {
+                content_type,
+                status,
+            } => write!(f, "unexpected content type `{}` ({})", content_type, status),
+            ResponseError::ApiErr(err) => match &err.msg {
+                Some(msg) => write!(f, "{}", msg),
+                None => write!(f, "the API returned an error without a message"),
+            },
         }
     }
 }
@@ -50,7 +115,14 @@ pub enum ClientCreationError {
     InvalidHeaderValue(String),
 }
 
-impl std::error::Error for ClientCreationError {}
+impl std::error::Error for ClientCreationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientCreationError::BuildErr(err) => Some(err),
+            ClientCreationError::InvalidHeaderValue(_) => None,
+        }
+    }
+}
 
 impl fmt::Display for ClientCreationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -64,4 +136,82 @@ impl fmt::Display for ClientCreationError {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    fn reqwest_error() -> reqwest::Error {
+        reqwest::Client::new().get("not a url").build().unwrap_err()
+    }
+
+    fn serde_json_error() -> serde_json::Error {
+        serde_json::from_str::<serde_json::Value>("{").unwrap_err()
+    }
+
+    #[test]
+    fn response_error_source_returns_wrapped_error() {
+        assert!(ResponseError::RequestErr(reqwest_error())
+            .source()
+            .is_some());
+        assert!(
+            ResponseError::DeserializeErr(serde_json_error(), "{".to_string())
+                .source()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn response_error_source_returns_none_if_not_wrapping() {
+        assert!(ResponseError::HttpErr(StatusCode::NOT_FOUND)
+            .source()
+            .is_none());
+        assert!(ResponseError::InvalidUserId("rinrin-rs".to_string())
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn response_error_displays_message() {
+        assert_eq!(
+            ResponseError::HttpErr(StatusCode::NOT_FOUND).to_string(),
+            "404 Not Found"
+        );
+        assert_eq!(
+            ResponseError::InvalidUserId("rinrin-rs".to_string()).to_string(),
+            "invalid user ID `rinrin-rs`"
+        );
+        assert_eq!(
+            ResponseError::InvalidRevolutionId("garbage".to_string()).to_string(),
+            "invalid Revolution ID `garbage`"
+        );
+        assert_eq!(
+            ResponseError::UnexpectedContentType {
+                content_type: "text/html".to_string(),
+                status: StatusCode::SERVICE_UNAVAILABLE
+            }
+            .to_string(),
+            "unexpected content type `text/html` (503 Service Unavailable)"
+        );
+        assert_eq!(
+            ResponseError::UnsupportedLeaderboard(Gamemode::League, LeaderboardType::Progression)
+                .to_string(),
+            "the personal leaderboard `progression` is not available for the game mode `league`"
+        );
+        let err = ResponseError::RequestErr(reqwest_error());
+        assert_eq!(err.to_string(), err.source().unwrap().to_string());
+        assert_eq!(
+            ResponseError::DeserializeErr(serde_json_error(), "{".to_string()).to_string(),
+            "EOF while parsing an object at line 1 column 1 (response body: `{`)"
+        );
+    }
+
+    #[test]
+    fn client_creation_error_source_returns_wrapped_error() {
+        assert!(ClientCreationError::BuildErr(reqwest_error())
+            .source()
+            .is_some());
+        let err = ClientCreationError::InvalidHeaderValue("\n".to_string());
+        assert!(err.source().is_none());
+        assert_eq!(err.to_string(), "failed to parse header value `\n`");
+    }
+}
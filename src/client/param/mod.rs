@@ -5,4 +5,5 @@ pub mod pagination;
 pub mod record;
 pub mod record_leaderboard;
 pub mod search_user;
+pub mod summary;
 pub mod user_leaderboard;
@@ -6,6 +6,7 @@
 //! [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
 
 use serde::Deserialize;
+use std::fmt;
 
 /// A prisecter.
 ///
@@ -16,7 +17,7 @@ use serde::Deserialize;
 /// Remember to pass an `X-Session-ID` header using the [`Client::with_session_id`](crate::client::Client::with_session_id) to ensure data consistency.  
 /// For more details, see the example in
 /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Prisecter {
     /// The primary sort key.
     pub pri: f64,
@@ -84,6 +85,163 @@ impl Bound {
         }
     }
 }
+
+/// Builds the query parameters of a search criteria.
+///
+/// The parameters are always in the order of the bound, the limit, and the country,
+/// so equivalent search criteria produce identical queries.
+/// The country code is uppercased.
+pub(crate) fn build_query(
+    bound: Option<Bound>,
+    limit: Option<u8>,
+    country: Option<String>,
+) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    if let Some(b) = bound {
+        result.push(b.to_query_param());
+    }
+    if let Some(l) = limit {
+        result.push(("limit".to_string(), l.to_string()));
+    }
+    if let Some(c) = country {
+        result.push(("country".to_string(), c.to_uppercase()));
+    }
+    result
+}
+
+/// An error which can be returned when the limit of a search criteria is not between 1 and 100.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidLimitError(pub(crate) u8);
+
+impl std::error::Error for InvalidLimitError {}
+
+impl fmt::Display for InvalidLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid limit `{}`, must be between 1 and 100", self.0)
+    }
+}
+
+/// A macro to implement the conversions into a search criteria.
+///
+/// # Implementations
+///
+/// ```ignore
+/// impl From<[f64; 3]> for $criteria
+/// impl TryFrom<(Bound, u8)> for $criteria
+/// ```
+///
+/// # Dependencies
+///
+/// - `bound: Option<Bound>` field
+/// - `new()`, `after()` and `limit()` methods
+macro_rules! impl_search_criteria_from {
+    ($criteria:ty) => {
+        impl From<[f64; 3]> for $criteria {
+            /// Creates a new search criteria with the specified upper bound.
+            /// The other values are set to default.
+            fn from(bound: [f64; 3]) -> Self {
+                Self::new().after(bound)
+            }
+        }
+
+        impl TryFrom<($crate::client::param::pagination::Bound, u8)> for $criteria {
+            type Error = $crate::client::param::pagination::InvalidLimitError;
+
+            /// Creates a new search criteria with the specified bound and limit.
+            /// The other values are set to default.
+            ///
+            /// # Errors
+            ///
+            /// Returns an [`InvalidLimitError`](crate::client::param::pagination::InvalidLimitError)
+            /// if the limit is not between 1 and 100.
+            fn try_from(
+                (bound, limit): ($crate::client::param::pagination::Bound, u8),
+            ) -> Result<Self, Self::Error> {
+                if !(1..=100).contains(&limit) {
+                    return Err($crate::client::param::pagination::InvalidLimitError(limit));
+                }
+                Ok(Self {
+                    bound: Some(bound),
+                    ..Self::new().limit(limit)
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_search_criteria_from;
+
+/// A macro to implement the in-place setters for a search criteria.
+///
+/// # Methods
+///
+/// ```ignore
+/// pub fn set_after(&mut self, bound: [f64; 3])
+/// pub fn set_before(&mut self, bound: [f64; 3])
+/// pub fn set_limit(&mut self, limit: u8)
+/// ```
+///
+/// # Dependencies
+///
+/// - `bound: Option<Bound>` field
+/// - `limit: Option<u8>` field
+///
+/// The argument is the name of the module which contains the search criteria,
+/// and is used in the examples.
+macro_rules! impl_search_criteria_setters {
+    ($module:ident) => {
+        /// Sets the upper bound in place.
+        ///
+        /// This is the mutating version of [`SearchCriteria::after`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("# use tetr_ch::client::param::", stringify!($module), "::SearchCriteria;")]
+        /// let mut criteria = SearchCriteria::new();
+        /// criteria.set_after([500000.0, 0.0, 0.0]);
+        /// ```
+        pub fn set_after(&mut self, bound: [f64; 3]) {
+            self.bound = Some($crate::client::param::pagination::Bound::After(bound));
+        }
+
+        /// Sets the lower bound in place.
+        ///
+        /// This is the mutating version of [`SearchCriteria::before`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("# use tetr_ch::client::param::", stringify!($module), "::SearchCriteria;")]
+        /// let mut criteria = SearchCriteria::new();
+        /// criteria.set_before([500000.0, 0.0, 0.0]);
+        /// ```
+        pub fn set_before(&mut self, bound: [f64; 3]) {
+            self.bound = Some($crate::client::param::pagination::Bound::Before(bound));
+        }
+
+        /// Limits the amount of entries to return in place.
+        ///
+        /// This is the mutating version of [`SearchCriteria::limit`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("# use tetr_ch::client::param::", stringify!($module), "::SearchCriteria;")]
+        /// let mut criteria = SearchCriteria::new();
+        /// criteria.set_limit(10);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if the argument `limit` is not between `1` and `100`.
+        pub fn set_limit(&mut self, limit: u8) {
+            $crate::util::validate_limit(limit);
+            self.limit = Some(limit);
+        }
+    };
+}
+pub(crate) use impl_search_criteria_setters;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +275,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_query_orders_bound_limit_and_country() {
+        let query = build_query(
+            Some(Bound::Before([1.0, 2.0, 3.0])),
+            Some(10),
+            Some("jp".to_string()),
+        );
+        assert_eq!(
+            query,
+            [
+                ("before".to_string(), "1:2:3".to_string()),
+                ("limit".to_string(), "10".to_string()),
+                ("country".to_string(), "JP".to_string())
+            ]
+        );
+        assert!(build_query(None, None, None).is_empty());
+    }
+
+    #[test]
+    fn search_criteria_build_is_identical_across_types() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+
+        let expected = build_query(Some(Bound::After([500000., 0., 0.])), Some(3), None);
+        // The setters are called in different orders on purpose.
+        let r = record::SearchCriteria::new()
+            .limit(3)
+            .after([500000., 0., 0.]);
+        let rl = record_leaderboard::SearchCriteria::new()
+            .after([500000., 0., 0.])
+            .limit(3);
+        let ul = user_leaderboard::SearchCriteria::new()
+            .limit(3)
+            .after([500000., 0., 0.]);
+        assert_eq!(r.build(), expected);
+        assert_eq!(rl.build(), expected);
+        assert_eq!(ul.build(), expected);
+    }
+
     #[test]
     fn bound_before_to_query_param_converts_into_query_param() {
         let bound = Bound::Before([12345.678, 0.0, 0.0]);
@@ -125,4 +321,91 @@ mod tests {
             ("before".to_string(), "12345.678:0:0".to_string())
         );
     }
+
+    #[test]
+    fn search_criteria_from_array_sets_upper_bound() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+
+        let expected = build_query(Some(Bound::After([500000., 0., 0.])), None, None);
+        assert_eq!(
+            record::SearchCriteria::from([500000., 0., 0.]).build(),
+            expected
+        );
+        assert_eq!(
+            record_leaderboard::SearchCriteria::from([500000., 0., 0.]).build(),
+            expected
+        );
+        assert_eq!(
+            user_leaderboard::SearchCriteria::from([500000., 0., 0.]).build(),
+            expected
+        );
+    }
+
+    #[test]
+    fn search_criteria_try_from_bound_and_limit_sets_both() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+
+        let bound = || Bound::Before([500000., 0., 0.]);
+        let expected = build_query(Some(bound()), Some(10), None);
+        let r = record::SearchCriteria::try_from((bound(), 10)).unwrap();
+        let rl = record_leaderboard::SearchCriteria::try_from((bound(), 10)).unwrap();
+        let ul = user_leaderboard::SearchCriteria::try_from((bound(), 10)).unwrap();
+        assert_eq!(r.build(), expected);
+        assert_eq!(rl.build(), expected);
+        assert_eq!(ul.build(), expected);
+    }
+
+    #[test]
+    fn search_criteria_try_from_bound_and_limit_rejects_out_of_range() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+
+        let bound = || Bound::After([0., 0., 0.]);
+        let err = record::SearchCriteria::try_from((bound(), 0)).unwrap_err();
+        assert_eq!(err, InvalidLimitError(0));
+        assert_eq!(
+            err.to_string(),
+            "invalid limit `0`, must be between 1 and 100"
+        );
+        assert_eq!(
+            record_leaderboard::SearchCriteria::try_from((bound(), 101)).unwrap_err(),
+            InvalidLimitError(101)
+        );
+        assert_eq!(
+            user_leaderboard::SearchCriteria::try_from((bound(), 0)).unwrap_err(),
+            InvalidLimitError(0)
+        );
+    }
+
+    #[test]
+    fn search_criteria_setters_match_builder() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+
+        macro_rules! assert_setters_match_builder {
+            ($criteria:ty) => {
+                let chained = <$criteria>::new().after([500000., 0., 0.]).limit(3);
+                let mut mutated = <$criteria>::new();
+                mutated.set_after([500000., 0., 0.]);
+                mutated.set_limit(3);
+                assert_eq!(mutated.clone().build(), chained.build());
+                mutated.set_before([1., 2., 3.]);
+                assert_eq!(
+                    mutated.build(),
+                    <$criteria>::new().before([1., 2., 3.]).limit(3).build()
+                );
+            };
+        }
+        assert_setters_match_builder!(record::SearchCriteria);
+        assert_setters_match_builder!(record_leaderboard::SearchCriteria);
+        assert_setters_match_builder!(user_leaderboard::SearchCriteria);
+    }
+
+    #[test]
+    fn search_criteria_set_limit_panics_if_out_of_range() {
+        use crate::client::param::{record, record_leaderboard, user_leaderboard};
+        use std::panic::catch_unwind;
+
+        assert!(catch_unwind(|| record::SearchCriteria::new().set_limit(0)).is_err());
+        assert!(catch_unwind(|| record_leaderboard::SearchCriteria::new().set_limit(101)).is_err());
+        assert!(catch_unwind(|| user_leaderboard::SearchCriteria::new().set_limit(0)).is_err());
+    }
 }
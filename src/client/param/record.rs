@@ -1,7 +1,10 @@
 //! Features for records.
 
-use super::pagination::Bound;
+use super::pagination::{
+    build_query, impl_search_criteria_from, impl_search_criteria_setters, Bound,
+};
 use crate::util::validate_limit;
+use std::fmt;
 
 /// A game mode of a record.
 #[derive(Clone, Debug)]
@@ -15,6 +18,9 @@ pub enum Gamemode {
     /// EXPERT QUICK PLAY.
     ZenithEx,
     /// TETRA LEAGUE history.
+    ///
+    /// The results of these records are
+    /// [`Results::MultiPlayer`](crate::model::summary::record::Results::MultiPlayer).
     League,
 }
 
@@ -46,6 +52,48 @@ impl Gamemode {
         }
         .to_string()
     }
+
+    /// Parses a parameter string, such as `40l`.
+    ///
+    /// If the string is not a game mode of the records, `None` is returned.
+    pub(crate) fn from_param(param: &str) -> Option<Self> {
+        match param {
+            "40l" => Some(Gamemode::FortyLines),
+            "blitz" => Some(Gamemode::Blitz),
+            "zenith" => Some(Gamemode::Zenith),
+            "zenithex" => Some(Gamemode::ZenithEx),
+            "league" => Some(Gamemode::League),
+            _ => None,
+        }
+    }
+
+    /// Returns the human-readable name of the game mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::client::param::record::Gamemode;
+    /// assert_eq!(Gamemode::FortyLines.display_name(), "40 LINES");
+    /// assert_eq!(Gamemode::Blitz.display_name(), "BLITZ");
+    /// assert_eq!(Gamemode::Zenith.display_name(), "QUICK PLAY");
+    /// assert_eq!(Gamemode::ZenithEx.display_name(), "EXPERT QUICK PLAY");
+    /// assert_eq!(Gamemode::League.display_name(), "TETRA LEAGUE");
+    /// ```
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Gamemode::FortyLines => "40 LINES",
+            Gamemode::Blitz => "BLITZ",
+            Gamemode::Zenith => "QUICK PLAY",
+            Gamemode::ZenithEx => "EXPERT QUICK PLAY",
+            Gamemode::League => "TETRA LEAGUE",
+        }
+    }
+}
+
+impl fmt::Display for Gamemode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_param())
+    }
 }
 
 /// A record leaderboard type.
@@ -81,10 +129,38 @@ impl LeaderboardType {
         }
         .to_string()
     }
+
+    /// Whether the personal leaderboard is available for the specified game mode.
+    ///
+    /// All the personal leaderboards are available for all the game modes,
+    /// except [`LeaderboardType::Progression`] for [`Gamemode::League`],
+    /// since TETRA LEAGUE records have no personal bests.
+    ///
+    /// # Arguments
+    ///
+    /// - `gamemode` - The game mode to check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::client::param::record::{Gamemode, LeaderboardType};
+    /// assert!(LeaderboardType::Progression.is_available_for(&Gamemode::FortyLines));
+    /// assert!(LeaderboardType::Recent.is_available_for(&Gamemode::League));
+    /// assert!(!LeaderboardType::Progression.is_available_for(&Gamemode::League));
+    /// ```
+    pub fn is_available_for(&self, gamemode: &Gamemode) -> bool {
+        !matches!(
+            (gamemode, self),
+            (Gamemode::League, LeaderboardType::Progression)
+        )
+    }
 }
 
 /// A search criteria for user records.
 ///
+/// The endpoint "User Personal Records" has no country parameter,
+/// so the records cannot be filtered by country.
+///
 /// # Examples
 ///
 /// ```
@@ -111,12 +187,15 @@ impl LeaderboardType {
 pub struct SearchCriteria {
     /// The bound to paginate.
     pub bound: Option<Bound>,
-    /// The amount of entries to return,
-    /// between 1 and 100. 25 by default.
+    /// The amount of entries to return, between 1 and 100.
+    /// If `None`, the server default ([`SearchCriteria::DEFAULT_LIMIT`]) is used.
     pub limit: Option<u8>,
 }
 
 impl SearchCriteria {
+    /// The amount of entries the server returns if the `limit` field is `None`.
+    pub const DEFAULT_LIMIT: u8 = 25;
+
     /// Creates a new [`SearchCriteria`].
     /// The values are set to default.
     ///
@@ -233,6 +312,8 @@ impl SearchCriteria {
         }
     }
 
+    impl_search_criteria_setters!(record);
+
     /// # Panics
     ///
     /// Panics if the limit is not between 1 and 100.
@@ -252,21 +333,24 @@ impl SearchCriteria {
     /// let query_params = criteria.build();
     /// ```
     pub(crate) fn build(self) -> Vec<(String, String)> {
-        let mut result = Vec::new();
-        if let Some(b) = self.bound {
-            result.push(b.to_query_param());
-        }
-        if let Some(l) = self.limit {
-            result.push(("limit".to_string(), l.to_string()));
-        }
-        result
+        build_query(self.bound, self.limit, None)
     }
 }
 
+impl_search_criteria_from!(SearchCriteria);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn search_criteria_default_limit_is_server_default() {
+        assert_eq!(SearchCriteria::DEFAULT_LIMIT, 25);
+        let criteria = SearchCriteria::new();
+        assert!(criteria.limit.is_none());
+        assert!(!criteria.build().iter().any(|(k, _)| k == "limit"));
+    }
+
     #[test]
     fn gamemode_to_param_converts_into_param_str() {
         assert_eq!(Gamemode::FortyLines.to_param(), "40l");
@@ -276,6 +360,39 @@ mod tests {
         assert_eq!(Gamemode::League.to_param(), "league");
     }
 
+    #[test]
+    fn gamemode_display_prints_param_str() {
+        assert_eq!(format!("{}", Gamemode::Blitz), "blitz");
+        assert_eq!(format!("{}", Gamemode::FortyLines), "40l");
+        assert_eq!(Gamemode::ZenithEx.to_string(), "zenithex");
+    }
+
+    #[test]
+    fn gamemode_from_param_parses_param_str() {
+        for gm in [
+            Gamemode::FortyLines,
+            Gamemode::Blitz,
+            Gamemode::Zenith,
+            Gamemode::ZenithEx,
+            Gamemode::League,
+        ] {
+            assert_eq!(
+                Gamemode::from_param(&gm.to_param()).map(|gm| gm.to_param()),
+                Some(gm.to_param())
+            );
+        }
+        assert!(Gamemode::from_param("zen").is_none());
+    }
+
+    #[test]
+    fn gamemode_display_name_returns_human_readable_name() {
+        assert_eq!(Gamemode::FortyLines.display_name(), "40 LINES");
+        assert_eq!(Gamemode::Blitz.display_name(), "BLITZ");
+        assert_eq!(Gamemode::Zenith.display_name(), "QUICK PLAY");
+        assert_eq!(Gamemode::ZenithEx.display_name(), "EXPERT QUICK PLAY");
+        assert_eq!(Gamemode::League.display_name(), "TETRA LEAGUE");
+    }
+
     #[test]
     fn leaderboard_type_to_param_converts_into_param_str() {
         assert_eq!(LeaderboardType::Top.to_param(), "top");
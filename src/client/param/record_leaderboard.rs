@@ -1,6 +1,8 @@
 //! Features for record leaderboards.
 
-use super::pagination::Bound;
+use super::pagination::{
+    build_query, impl_search_criteria_from, impl_search_criteria_setters, Bound,
+};
 use crate::util::validate_limit;
 
 /// A record leaderboard ID.
@@ -37,6 +39,76 @@ impl RecordsLeaderboardId {
         }
     }
 
+    /// Parses a record leaderboard ID string, such as `40l_global` or `zenith_country_JP@2024w31`.
+    ///
+    /// This is the inverse of the conversion into a parameter,
+    /// so the strings in [`Record::leaderboards`](crate::model::summary::record::Record::leaderboards)
+    /// can be used to re-query the leaderboards.
+    ///
+    /// If the string is malformed, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `param` - The record leaderboard ID string to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::client::param::record_leaderboard::{RecordsLeaderboardId, Scope};
+    /// let id = RecordsLeaderboardId::from_param("zenith_country_JP@2024w31").unwrap();
+    /// assert_eq!(id.gamemode, "zenith");
+    /// assert!(matches!(id.scope, Scope::Country(c) if c == "JP"));
+    /// assert_eq!(id.revolution_id.as_deref(), Some("@2024w31"));
+    ///
+    /// assert!(RecordsLeaderboardId::from_param("40l").is_none());
+    /// ```
+    pub fn from_param(param: &str) -> Option<Self> {
+        let (id, revolution_id) = match param.find('@') {
+            Some(i) => (&param[..i], Some(&param[i..])),
+            None => (param, None),
+        };
+        if revolution_id == Some("@") {
+            return None;
+        }
+        let (gamemode, scope) = if let Some(gamemode) = id.strip_suffix("_global") {
+            (gamemode, Scope::Global)
+        } else {
+            let (gamemode, country) = id.split_once("_country_")?;
+            if country.is_empty() {
+                return None;
+            }
+            (gamemode, Scope::Country(country.to_string()))
+        };
+        if gamemode.is_empty() {
+            return None;
+        }
+        Some(Self::new(gamemode, scope, revolution_id))
+    }
+
+    /// Normalizes the Revolution ID, consuming the `self` value.
+    ///
+    /// The `@` is prepended to the Revolution ID if missing.
+    /// If the Revolution ID does not match the pattern `@YYYYwWW` (e.g. `@2024w31`),
+    /// the Revolution ID is returned as is as `Err<String>`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// # use tetr_ch::client::param::record_leaderboard::{RecordsLeaderboardId, Scope};
+    /// let id = RecordsLeaderboardId::new("zenith", Scope::Global, Some("2024w31"));
+    /// assert_eq!(id.normalize().unwrap().revolution_id.as_deref(), Some("@2024w31"));
+    /// ```
+    pub(crate) fn normalize(mut self) -> Result<Self, String> {
+        if let Some(revolution_id) = self.revolution_id.take() {
+            let id = revolution_id.strip_prefix('@').unwrap_or(&revolution_id);
+            if !is_valid_revolution_id(id) {
+                return Err(revolution_id);
+            }
+            self.revolution_id = Some(format!("@{}", id));
+        }
+        Ok(self)
+    }
+
     /// Converts into a parameter.
     ///
     /// # Examples
@@ -60,6 +132,18 @@ impl RecordsLeaderboardId {
     }
 }
 
+/// Whether the string matches the pattern `YYYYwWW` (e.g. `2024w31`).
+fn is_valid_revolution_id(id: &str) -> bool {
+    match id.split_once('w') {
+        Some((year, week)) => {
+            year.len() == 4
+                && (1..=2).contains(&week.len())
+                && year.chars().chain(week.chars()).all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
 /// A scope of record leaderboards.
 #[derive(Clone, Debug)]
 pub enum Scope {
@@ -73,6 +157,10 @@ pub enum Scope {
 
 /// A search criteria for the records leaderboard.
 ///
+/// The endpoint "Records Leaderboard" has no country parameter.
+/// To get a country-scoped leaderboard,
+/// use the [`Scope::Country`] in the [`RecordsLeaderboardId`] instead.
+///
 /// # Examples
 ///
 /// ```
@@ -99,19 +187,22 @@ pub enum Scope {
 pub struct SearchCriteria {
     /// The bound to paginate.
     pub bound: Option<Bound>,
-    /// The amount of entries to return,
-    /// between 1 and 100. 25 by default.
+    /// The amount of entries to return, between 1 and 100.
+    /// If `None`, the server default ([`SearchCriteria::DEFAULT_LIMIT`]) is used.
     pub limit: Option<u8>,
 }
 
 impl SearchCriteria {
+    /// The amount of entries the server returns if the `limit` field is `None`.
+    pub const DEFAULT_LIMIT: u8 = 25;
+
     /// Creates a new [`SearchCriteria`].
     /// The values are set to default.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new();
     /// ```
     pub fn new() -> Self {
@@ -123,7 +214,7 @@ impl SearchCriteria {
     /// # Examples
     ///
     /// ```
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let mut criteria = SearchCriteria::new();
     /// criteria.init();
     /// ```
@@ -147,7 +238,7 @@ impl SearchCriteria {
     /// Sets the upper bound to `[500000.0, 0.0, 0.0]`.
     ///
     /// ```
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new().after([500000.0, 0.0, 0.0]);
     /// ```
     pub fn after(self, bound: [f64; 3]) -> Self {
@@ -174,7 +265,7 @@ impl SearchCriteria {
     /// Sets the lower bound to `[500000.0, 0.0, 0.0]`.
     ///
     /// ```
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new().before([500000.0, 0.0, 0.0]);
     /// ```
     pub fn before(self, bound: [f64; 3]) -> Self {
@@ -196,7 +287,7 @@ impl SearchCriteria {
     /// Limits the amount of entries to return to `10`.
     ///
     /// ```
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new().limit(10);
     /// ```
     ///
@@ -205,12 +296,12 @@ impl SearchCriteria {
     /// Panics if the argument `limit` is not between `1` and `100`.
     ///
     /// ```should_panic
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new().limit(0);
     /// ```
     ///
     /// ```should_panic
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new().limit(101);
     /// ```
     pub fn limit(self, limit: u8) -> Self {
@@ -221,6 +312,8 @@ impl SearchCriteria {
         }
     }
 
+    impl_search_criteria_setters!(record_leaderboard);
+
     /// # Panics
     ///
     /// Panics if the limit is not between 1 and 100.
@@ -235,26 +328,29 @@ impl SearchCriteria {
     /// # Examples
     ///
     /// ```ignore
-    /// # use tetr_ch::client::param::record::SearchCriteria;
+    /// # use tetr_ch::client::param::record_leaderboard::SearchCriteria;
     /// let criteria = SearchCriteria::new();
     /// let query_params = criteria.build();
     /// ```
     pub(crate) fn build(self) -> Vec<(String, String)> {
-        let mut result = Vec::new();
-        if let Some(b) = self.bound {
-            result.push(b.to_query_param());
-        }
-        if let Some(l) = self.limit {
-            result.push(("limit".to_string(), l.to_string()));
-        }
-        result
+        build_query(self.bound, self.limit, None)
     }
 }
 
+impl_search_criteria_from!(SearchCriteria);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn search_criteria_default_limit_is_server_default() {
+        assert_eq!(SearchCriteria::DEFAULT_LIMIT, 25);
+        let criteria = SearchCriteria::new();
+        assert!(criteria.limit.is_none());
+        assert!(!criteria.build().iter().any(|(k, _)| k == "limit"));
+    }
+
     #[test]
     fn records_leaderboard_id_new_creates_default() {
         let id = RecordsLeaderboardId::new("40l", Scope::Global, None);
@@ -273,6 +369,75 @@ mod tests {
         assert_eq!(id3.to_param(), "zenith_global@2024w31");
     }
 
+    #[test]
+    fn records_leaderboard_id_normalize_prepends_at_sign() {
+        let id = RecordsLeaderboardId::new("zenith", Scope::Global, Some("2024w31"));
+        let id = id.normalize().unwrap();
+        assert_eq!(id.revolution_id.as_deref(), Some("@2024w31"));
+        assert_eq!(id.to_param(), "zenith_global@2024w31");
+    }
+
+    #[test]
+    fn records_leaderboard_id_normalize_keeps_valid_revolution_id() {
+        let id = RecordsLeaderboardId::new("zenith", Scope::Global, Some("@2024w31"));
+        assert_eq!(
+            id.normalize().unwrap().revolution_id.as_deref(),
+            Some("@2024w31")
+        );
+        let id = RecordsLeaderboardId::new("40l", Scope::Global, None);
+        assert!(id.normalize().unwrap().revolution_id.is_none());
+    }
+
+    #[test]
+    fn records_leaderboard_id_normalize_returns_malformed_revolution_id() {
+        for revolution_id in ["garbage", "@", "@24w31", "@2024w", "@2024w123", "@2024-31"] {
+            let id = RecordsLeaderboardId::new("zenith", Scope::Global, Some(revolution_id));
+            assert_eq!(id.normalize().unwrap_err(), revolution_id);
+        }
+    }
+
+    #[test]
+    fn records_leaderboard_id_from_param_round_trips() {
+        for param in [
+            "40l_global",
+            "blitz_country_JP",
+            "zenith_global@2024w31",
+            "zenithex_country_US@2024w31",
+        ] {
+            let id = RecordsLeaderboardId::from_param(param).unwrap();
+            assert_eq!(id.to_param(), param);
+        }
+    }
+
+    #[test]
+    fn records_leaderboard_id_from_param_parses_fields() {
+        let id = RecordsLeaderboardId::from_param("blitz_country_JP@2024w31").unwrap();
+        assert_eq!(id.gamemode, "blitz");
+        assert!(matches!(id.scope, Scope::Country(c) if c == "JP"));
+        assert_eq!(id.revolution_id.as_deref(), Some("@2024w31"));
+        let id = RecordsLeaderboardId::from_param("40l_global").unwrap();
+        assert!(matches!(id.scope, Scope::Global));
+        assert!(id.revolution_id.is_none());
+    }
+
+    #[test]
+    fn records_leaderboard_id_from_param_returns_none_if_malformed() {
+        for param in [
+            "",
+            "40l",
+            "_global",
+            "40l_country_",
+            "40l_local",
+            "40l_global@",
+        ] {
+            assert!(
+                RecordsLeaderboardId::from_param(param).is_none(),
+                "{}",
+                param
+            );
+        }
+    }
+
     #[test]
     fn search_criteria_new_creates_default() {
         let criteria: SearchCriteria = SearchCriteria::new();
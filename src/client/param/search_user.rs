@@ -1,15 +1,80 @@
 //! Features for the [`Client::search_user`](crate::client::Client::search_user) method.
 
+use crate::model::user::Platform;
+use std::fmt;
+
 /// A social connection.
 ///
-/// [API document](https://tetr.io/about/api/#userssearchquery) says searching for the other social links will be added in the near future.
-#[derive(Clone, Debug)]
+/// The variants follow the third party platforms that a user can connect to.
+/// (See [`Connections`](crate::model::user::Connections).)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SocialConnection {
     /// A Discord ID.
     Discord(String),
+    /// A Twitch user ID.
+    Twitch(String),
+    /// An X user ID.
+    Twitter(String),
+    /// A Reddit user ID.
+    Reddit(String),
+    /// A YouTube user ID.
+    YouTube(String),
+    /// A SteamID.
+    Steam(String),
 }
 
 impl SocialConnection {
+    /// Creates a new [`SocialConnection`] from the platform and the user ID on it.
+    ///
+    /// # Arguments
+    ///
+    /// - `platform` - The platform of the social connection.
+    /// - `id` - The user ID on the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::{client::param::search_user::SocialConnection, model::user::Platform};
+    /// let connection = SocialConnection::from_platform_and_id(Platform::Discord, "724976600873041940");
+    /// assert_eq!(connection, SocialConnection::Discord("724976600873041940".to_string()));
+    /// ```
+    pub fn from_platform_and_id(platform: Platform, id: &str) -> Self {
+        let id = id.to_string();
+        match platform {
+            Platform::Discord => SocialConnection::Discord(id),
+            Platform::Twitch => SocialConnection::Twitch(id),
+            Platform::Twitter => SocialConnection::Twitter(id),
+            Platform::Reddit => SocialConnection::Reddit(id),
+            Platform::YouTube => SocialConnection::YouTube(id),
+            Platform::Steam => SocialConnection::Steam(id),
+        }
+    }
+
+    /// Returns the platform of the social connection.
+    pub fn platform(&self) -> Platform {
+        match self {
+            SocialConnection::Discord(_) => Platform::Discord,
+            SocialConnection::Twitch(_) => Platform::Twitch,
+            SocialConnection::Twitter(_) => Platform::Twitter,
+            SocialConnection::Reddit(_) => Platform::Reddit,
+            SocialConnection::YouTube(_) => Platform::YouTube,
+            SocialConnection::Steam(_) => Platform::Steam,
+        }
+    }
+
+    /// Returns the user ID on the platform.
+    pub fn id(&self) -> &str {
+        match self {
+            SocialConnection::Discord(id)
+            | SocialConnection::Twitch(id)
+            | SocialConnection::Twitter(id)
+            | SocialConnection::Reddit(id)
+            | SocialConnection::YouTube(id)
+            | SocialConnection::Steam(id) => id,
+        }
+    }
+
     /// Converts into a parameter string.
     ///
     /// # Examples
@@ -20,9 +85,21 @@ impl SocialConnection {
     /// assert_eq!(connection.to_param(), "discord:724976600873041940");
     /// ```
     pub(crate) fn to_param(&self) -> String {
-        match self {
-            SocialConnection::Discord(id) => format!("discord:{}", id),
-        }
+        let service = match self {
+            SocialConnection::Discord(_) => "discord",
+            SocialConnection::Twitch(_) => "twitch",
+            SocialConnection::Twitter(_) => "twitter",
+            SocialConnection::Reddit(_) => "reddit",
+            SocialConnection::YouTube(_) => "youtube",
+            SocialConnection::Steam(_) => "steam",
+        };
+        format!("{}:{}", service, self.id())
+    }
+}
+
+impl fmt::Display for SocialConnection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_param())
     }
 }
 
@@ -35,4 +112,27 @@ mod tests {
         let connection = SocialConnection::Discord("724976600873041940".to_string());
         assert_eq!(connection.to_param(), "discord:724976600873041940");
     }
+
+    #[test]
+    fn social_connection_round_trips_for_each_platform() {
+        let cases = [
+            (Platform::Discord, "discord"),
+            (Platform::Twitch, "twitch"),
+            (Platform::Twitter, "twitter"),
+            (Platform::Reddit, "reddit"),
+            (Platform::YouTube, "youtube"),
+            (Platform::Steam, "steam"),
+        ];
+        for (platform, service) in cases {
+            let connection = SocialConnection::from_platform_and_id(platform, "12345");
+            assert_eq!(connection.platform(), platform);
+            assert_eq!(connection.id(), "12345");
+            assert_eq!(connection.to_param(), format!("{}:12345", service));
+            assert_eq!(connection.to_string(), connection.to_param());
+            assert_eq!(
+                SocialConnection::from_platform_and_id(connection.platform(), connection.id()),
+                connection
+            );
+        }
+    }
 }
@@ -0,0 +1,20 @@
+//! Features for the [`Client::get_user_summaries`](crate::client::Client::get_user_summaries) method.
+
+/// A kind of the user summaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SummaryKind {
+    /// 40 LINES
+    FortyLines,
+    /// BLITZ
+    Blitz,
+    /// QUICK PLAY
+    Zenith,
+    /// EXPERT QUICK PLAY
+    ZenithEx,
+    /// TETRA LEAGUE
+    League,
+    /// ZEN
+    Zen,
+    /// Achievements
+    Achievements,
+}
@@ -1,7 +1,9 @@
 //! Features for user leaderboards.
 
-use super::pagination::Bound;
-use crate::util::{encode, validate_limit};
+use super::pagination::{
+    build_query, impl_search_criteria_from, impl_search_criteria_setters, Bound,
+};
+use crate::util::validate_limit;
 
 /// A user leaderboard type.
 #[derive(Clone, Debug)]
@@ -63,15 +65,20 @@ impl LeaderboardType {
 pub struct SearchCriteria {
     /// The bound to paginate.
     pub bound: Option<Bound>,
-    /// The amount of entries to return,
-    /// between 1 and 100. 25 by default.
+    /// The amount of entries to return, between 1 and 100.
+    /// If `None`, the server default ([`SearchCriteria::DEFAULT_LIMIT`]) is used.
     pub limit: Option<u8>,
     /// The ISO 3166-1 country code to filter to.
     /// Leave unset to not filter by country.
+    ///
+    /// The API accepts only one country code (see [`SearchCriteria::country`]).
     pub country: Option<String>,
 }
 
 impl SearchCriteria {
+    /// The amount of entries the server returns if the `limit` field is `None`.
+    pub const DEFAULT_LIMIT: u8 = 25;
+
     /// Creates a new [`SearchCriteria`].
     /// The values are set to default.
     ///
@@ -154,6 +161,12 @@ impl SearchCriteria {
 
     /// Limits the amount of entries to return.
     ///
+    /// The endpoint "User Leaderboard" has no option to export the full leaderboard at once
+    /// (unlike the legacy `limit=full` of the TETRA LEAGUE leaderboard).
+    /// To get all the entries, paginate using the bounds.
+    /// For more details, see the example in
+    /// [`15_pagination-for-leaderboard.rs`](https://github.com/Rinrin0413/tetr-ch-rs/tree/master/examples/15_pagination-for-leaderboard.rs).
+    ///
     /// # Arguments
     ///
     /// - `limit` - The amount of entries to return.
@@ -189,12 +202,19 @@ impl SearchCriteria {
         }
     }
 
+    impl_search_criteria_setters!(user_leaderboard);
+
     /// Sets the ISO 3166-1 country code to filter to.
     ///
     /// # Arguments
     ///
     /// - `country` - The ISO 3166-1 country code to filter to.
     ///
+    /// The API filters to only one country,
+    /// so calling this again replaces the previous country code.
+    /// To get the entries of multiple countries,
+    /// send a request for each country and merge the results.
+    ///
     /// # Examples
     ///
     /// Sets the country code to `jp`.
@@ -229,24 +249,24 @@ impl SearchCriteria {
     /// let query_params = criteria.build();
     /// ```
     pub(crate) fn build(self) -> Vec<(String, String)> {
-        let mut result = Vec::new();
-        if let Some(b) = self.bound {
-            result.push(b.to_query_param());
-        }
-        if let Some(l) = self.limit {
-            result.push(("limit".to_string(), l.to_string()));
-        }
-        if let Some(c) = self.country {
-            result.push(("country".to_string(), encode(c.to_uppercase())));
-        }
-        result
+        build_query(self.bound, self.limit, self.country)
     }
 }
 
+impl_search_criteria_from!(SearchCriteria);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn search_criteria_default_limit_is_server_default() {
+        assert_eq!(SearchCriteria::DEFAULT_LIMIT, 25);
+        let criteria = SearchCriteria::new();
+        assert!(criteria.limit.is_none());
+        assert!(!criteria.build().iter().any(|(k, _)| k == "limit"));
+    }
+
     #[test]
     fn leaderboard_type_to_param_converts_into_param_str() {
         assert_eq!(LeaderboardType::League.to_param(), "league");
@@ -1,16 +1,49 @@
 use super::error::{ResponseError, RspErr};
-use reqwest::{Error, Response};
+use crate::model::response::Response as ApiResponse;
+use reqwest::{header, Error, Response};
 use serde::Deserialize;
+use std::fmt;
 
 /// Receives a `Result<Response, Error>` and returns a `Result<T, ResponseError>`.
 ///
+/// The `ETag` header of the response is stored in the returned value.
+///
+/// If the `tracing` feature is enabled,
+/// events about the request URL, the status code and the cache status are emitted.
+///
 /// # Examples
 ///
 /// ```ignore
 /// let res = self.client.get(url).send().await;
 /// response(res).await
 /// ```
-pub(super) async fn process_response<T>(response: Result<Response, Error>) -> RspErr<T>
+pub(super) async fn process_response<T>(response: Result<Response, Error>) -> RspErr<ApiResponse<T>>
+where
+    for<'de> T: Deserialize<'de> + Clone + fmt::Debug + AsRef<T>,
+{
+    let etag = response.as_ref().ok().and_then(|r| {
+        r.headers()
+            .get(header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from)
+    });
+    let mut m = process_json::<ApiResponse<T>>(response).await?;
+    m.etag = etag;
+    #[cfg(feature = "tracing")]
+    if let Some(cache) = &m.cache {
+        tracing::debug!(cache = %cache.status, "cache status");
+    }
+    Ok(m)
+}
+
+/// Receives a `Result<Response, Error>` and deserializes the response body as JSON.
+///
+/// If the `Content-Type` header is present but not JSON (e.g. an HTML error page),
+/// a [`ResponseError::UnexpectedContentType`] is returned without reading the body.
+///
+/// If the `tracing` feature is enabled,
+/// events about the request URL and the status code are emitted.
+pub(super) async fn process_json<T>(response: Result<Response, Error>) -> RspErr<T>
 where
     for<'de> T: Deserialize<'de>,
 {
@@ -19,19 +52,72 @@ where
         Ok(r) => {
             let status = r.status();
             let is_success = status.is_success();
+            #[cfg(feature = "tracing")]
+            tracing::debug!(url = %r.url(), status = status.as_u16(), "received a response");
+            if let Some(content_type) = r
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .filter(|v| !v.contains("json"))
+            {
+                return Err(ResponseError::UnexpectedContentType {
+                    content_type: content_type.to_string(),
+                    status,
+                });
+            }
+            let body = match r.bytes().await {
+                Ok(b) => b,
+                Err(e) => return Err(ResponseError::RequestErr(e)),
+            };
             // Whether the response is an expected structure or not.
-            match r.json().await {
+            match serde_json::from_slice::<T>(&body) {
                 Ok(m) => Ok(m),
                 Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %e, "failed to deserialize the response");
                     // Whether the status code is within 200-299 or not.
                     if is_success {
-                        Err(ResponseError::DeserializeErr(e))
+                        Err(ResponseError::DeserializeErr(e, body_excerpt(&body)))
                     } else {
                         Err(ResponseError::HttpErr(status))
                     }
                 }
             }
         }
-        Err(e) => Err(ResponseError::RequestErr(e)),
+        Err(e) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(url = ?e.url().map(|u| u.as_str()), error = %e, "request failed");
+            Err(ResponseError::RequestErr(e))
+        }
+    }
+}
+
+/// The maximum length of the response body excerpt in bytes.
+const BODY_EXCERPT_LEN: usize = 256;
+
+/// Returns the beginning of the response body, for the error messages.
+///
+/// If the body is longer than [`BODY_EXCERPT_LEN`], it is truncated with `...`.
+fn body_excerpt(body: &[u8]) -> String {
+    if body.len() <= BODY_EXCERPT_LEN {
+        String::from_utf8_lossy(body).into_owned()
+    } else {
+        format!("{}...", String::from_utf8_lossy(&body[..BODY_EXCERPT_LEN]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn body_excerpt_truncates_long_body() {
+        assert_eq!(
+            body_excerpt(b"{ \"success\": true }"),
+            "{ \"success\": true }"
+        );
+        let excerpt = body_excerpt(&[b'a'; 1000]);
+        assert_eq!(excerpt.len(), BODY_EXCERPT_LEN + 3);
+        assert!(excerpt.ends_with("a..."));
     }
 }
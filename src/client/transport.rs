@@ -0,0 +1,62 @@
+//! A transport for the [`Client`](crate::client::Client).
+
+pub use futures_util::future::BoxFuture;
+pub use reqwest::{Error, Request, Response};
+use std::sync::Arc;
+
+/// A transport that sends the requests of the [`Client`](crate::client::Client).
+///
+/// By default, the requests are sent by [`reqwest::Client`].
+/// Implementing this trait allows to replace it, e.g. with an in-memory transport for testing.
+/// To use a custom transport, see [`Client::with_transport`](crate::client::Client::with_transport).
+///
+/// The requests passed to a transport already have all the headers set by the client,
+/// including the `X-Session-ID` header.
+///
+/// # Stability
+///
+/// The signature of this trait uses the types of [`reqwest`] and [`futures_util`] directly:
+/// [`Request`], [`Response`], [`Error`] and [`BoxFuture`].
+/// So a major version bump of those crates is a breaking change of this trait.
+/// These types are re-exported from this module,
+/// so custom transports can be implemented without depending on those crates directly.
+///
+/// # Examples
+///
+/// A transport that returns a canned response for every request.
+///
+/// ```
+/// use tetr_ch::{
+///     client::transport::{BoxFuture, Error, Request, Response, Transport},
+///     prelude::*,
+/// };
+///
+/// struct Canned;
+///
+/// impl Transport for Canned {
+///     fn send(&self, _request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+///         let rsp = http::Response::new(r#"{ "success": true, "data": 42 }"#);
+///         Box::pin(async move { Ok(rsp.into()) })
+///     }
+/// }
+///
+/// let client = Client::new().with_transport(Canned);
+/// ```
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>>;
+}
+
+impl Transport for reqwest::Client {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(self.execute(request))
+    }
+}
+
+/// Allows to keep a handle of the transport passed to the client,
+/// e.g. to inspect the requests sent by it.
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        (**self).send(request)
+    }
+}
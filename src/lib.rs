@@ -112,10 +112,29 @@ pub mod util;
 
 /// A prelude for the tetr-ch-rs.
 ///
+/// This also re-exports the frequently used models.
+///
 /// # Example
 ///
 /// ```
 /// use tetr_ch::prelude::*;
+///
+/// fn describe(response: Response<User>) -> Option<String> {
+///     let user = response.data?;
+///     Some(format!("{} ({})", user.username, user.id))
+/// }
+///
+/// fn top_rank(leaderboard: &Leaderboard) -> Option<&Rank> {
+///     leaderboard.iter().next().map(|u| &u.league.rank)
+/// }
+///
+/// fn game_mode(record: &Record) -> &Gamemode {
+///     &record.game_mode
+/// }
+///
+/// fn registered(stats: &ServerStats) -> u64 {
+///     stats.registered_players()
+/// }
 /// ```
 pub mod prelude {
     pub use crate::client::{
@@ -124,9 +143,18 @@ pub mod prelude {
             record::Gamemode as RecordGamemode,
             record_leaderboard::{RecordsLeaderboardId, Scope},
             search_user::SocialConnection,
+            summary::SummaryKind,
             user_leaderboard::LeaderboardType as UserLeaderboardType,
             *,
         },
         Client,
     };
+    pub use crate::model::{
+        leaderboard::Leaderboard,
+        response::Response,
+        server_stats::ServerStats,
+        summary::record::Record,
+        user::{BadgeSliceExt, User},
+        util::{Gamemode, Rank},
+    };
 }
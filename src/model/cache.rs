@@ -2,14 +2,18 @@
 //!
 //! For more details, see the [API document](https://tetr.io/about/api/#cachedata).
 
-use crate::model::prelude::*;
+use crate::{
+    model::prelude::*,
+    util::clock::{Clock, SystemClock},
+};
 
 /// Data about how a request was cached.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct CacheData {
     /// Whether the cache was hit.
     /// Either `"hit"`, `"miss"`, or `"awaited"`.
+    /// Any other status is kept as [`Status::Unknown`].
     /// `"awaited"` means resource was already being requested by another client.
     pub status: Status,
     /// When this resource was cached.
@@ -42,6 +46,61 @@ impl CacheData {
     pub fn cached_until(&self) -> i64 {
         self.cached_until as i64 / 1000
     }
+
+    /// Returns the remaining time until this resource's cache expires.
+    ///
+    /// If the cache has already expired, a negative duration is returned.
+    pub fn ttl(&self) -> chrono::Duration {
+        self.ttl_with(&SystemClock)
+    }
+
+    /// Whether this resource's cache has expired.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_with(&SystemClock)
+    }
+
+    /// Returns the remaining time until this resource's cache expires,
+    /// based on the time of the specified clock.
+    ///
+    /// If the cache has already expired, a negative duration is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::{model::cache::CacheData, util::clock::FixedClock};
+    ///
+    /// let cache_data: CacheData = serde_json::from_str(
+    ///     r#"{ "status": "hit", "cached_at": 1661710769000, "cached_until": 1661710844000 }"#,
+    /// )
+    /// .unwrap();
+    /// let fresh = FixedClock(1661710769000);
+    /// assert_eq!(cache_data.ttl_with(&fresh), chrono::Duration::seconds(75));
+    /// let expired = FixedClock(1661710849000);
+    /// assert_eq!(cache_data.ttl_with(&expired), chrono::Duration::seconds(-5));
+    /// ```
+    pub fn ttl_with(&self, clock: &(impl Clock + ?Sized)) -> chrono::Duration {
+        chrono::Duration::milliseconds(self.cached_until as i64 - clock.now_ms())
+    }
+
+    /// Whether this resource's cache has expired,
+    /// based on the time of the specified clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::{model::cache::CacheData, util::clock::FixedClock};
+    ///
+    /// let cache_data: CacheData = serde_json::from_str(
+    ///     r#"{ "status": "hit", "cached_at": 1661710769000, "cached_until": 1661710844000 }"#,
+    /// )
+    /// .unwrap();
+    /// // Fresh until the time of `cached_until`.
+    /// assert!(!cache_data.is_expired_with(&FixedClock(1661710769000)));
+    /// assert!(cache_data.is_expired_with(&FixedClock(1661710844000)));
+    /// ```
+    pub fn is_expired_with(&self, clock: &(impl Clock + ?Sized)) -> bool {
+        self.ttl_with(clock) <= chrono::Duration::zero()
+    }
 }
 
 impl AsRef<CacheData> for CacheData {
@@ -51,7 +110,7 @@ impl AsRef<CacheData> for CacheData {
 }
 
 /// A status of the cache.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum Status {
@@ -59,6 +118,9 @@ pub enum Status {
     Miss,
     /// Resource was already being requested by another client.
     Awaited,
+    /// A status not documented in the API document.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl AsRef<Status> for Status {
@@ -73,6 +135,7 @@ impl fmt::Display for Status {
             Status::Hit => write!(f, "hit"),
             Status::Miss => write!(f, "miss"),
             Status::Awaited => write!(f, "awaited"),
+            Status::Unknown(s) => write!(f, "{}", s),
         }
     }
 }
@@ -92,6 +155,70 @@ mod tests {
         assert_eq!(cache_data.cached_until(), 1661710844);
     }
 
+    #[test]
+    fn cached_until_returns_expiry_not_creation_time() {
+        let cache_data = CacheData {
+            status: Status::Miss,
+            cached_at: 1661710769000,
+            cached_until: 1661710844000,
+        };
+        assert!(cache_data.cached_until() > cache_data.cached_at());
+    }
+
+    #[test]
+    fn ttl_is_negative_and_expired_if_cached_until_is_past() {
+        let cache_data = CacheData {
+            status: Status::Hit,
+            cached_at: 1661710769000,
+            cached_until: 1661710844000,
+        };
+        assert!(cache_data.ttl() < chrono::Duration::zero());
+        assert!(cache_data.is_expired());
+    }
+
+    #[test]
+    fn ttl_is_positive_and_not_expired_if_cached_until_is_future() {
+        let now = SystemClock.now_ms() as u64;
+        let cache_data = CacheData {
+            status: Status::Miss,
+            cached_at: now,
+            cached_until: now + 60_000,
+        };
+        assert!(cache_data.ttl() > chrono::Duration::zero());
+        assert!(cache_data.ttl() <= chrono::Duration::seconds(60));
+        assert!(!cache_data.is_expired());
+    }
+
+    #[test]
+    fn is_expired_with_follows_clock() {
+        use crate::util::clock::FixedClock;
+
+        let cache_data = CacheData {
+            status: Status::Hit,
+            cached_at: 1661710769000,
+            cached_until: 1661710844000,
+        };
+        let clock = FixedClock(1661710769000);
+        assert_eq!(cache_data.ttl_with(&clock), chrono::Duration::seconds(75));
+        assert!(!cache_data.is_expired_with(&clock));
+        assert!(!cache_data.is_expired_with(&FixedClock(1661710843999)));
+        assert!(cache_data.is_expired_with(&FixedClock(1661710844000)));
+    }
+
+    #[test]
+    fn status_deserializes_each_status() {
+        for (json, status) in [
+            ("\"hit\"", Status::Hit),
+            ("\"miss\"", Status::Miss),
+            ("\"awaited\"", Status::Awaited),
+            ("\"stale\"", Status::Unknown("stale".to_string())),
+        ] {
+            let deserialized: Status = serde_json::from_str(json).unwrap();
+            assert_eq!(deserialized.to_string(), json.trim_matches('"'));
+            assert_eq!(deserialized, status);
+        }
+    }
+
     #[test]
     fn cache_data_as_ref() {
         let cache_data = CacheData {
@@ -0,0 +1,272 @@
+//! Models for the comparison of two users.
+//!
+//! - [`UserComparison`] is returned by the [`Client::compare_users`](crate::client::Client::compare_users) method.
+//! - [`UserDiff`] compares two snapshots of the same user.
+
+use crate::{
+    model::{summary::league::LeagueData, user::User, util::Rank},
+    util::league::expected_score,
+};
+
+/// A side-by-side comparison of two users.
+///
+/// All the deltas are calculated as `a - b`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct UserComparison {
+    /// The first user.
+    pub a: ComparedUser,
+    /// The second user.
+    pub b: ComparedUser,
+}
+
+impl UserComparison {
+    /// Creates a new [`UserComparison`].
+    pub fn new(a: ComparedUser, b: ComparedUser) -> Self {
+        Self { a, b }
+    }
+
+    /// Returns the difference in XP.
+    pub fn xp_delta(&self) -> f64 {
+        self.a.user.xp - self.b.user.xp
+    }
+
+    /// Returns the difference in level.
+    pub fn level_delta(&self) -> i64 {
+        self.a.user.level() as i64 - self.b.user.level() as i64
+    }
+
+    /// Returns the difference in TR (Tetra Rating).
+    ///
+    /// If either user is not rated, `None` is returned.
+    pub fn tr_delta(&self) -> Option<f64> {
+        Some(self.a.league()?.rating_opt()? - self.b.league()?.rating_opt()?)
+    }
+
+    /// Returns the difference in average APM (attack per minute) over the last 10 games.
+    ///
+    /// If either user has no APM, `None` is returned.
+    pub fn apm_delta(&self) -> Option<f64> {
+        Some(self.a.league()?.apm? - self.b.league()?.apm?)
+    }
+
+    /// Returns the difference in average PPS (pieces per second) over the last 10 games.
+    ///
+    /// If either user has no PPS, `None` is returned.
+    pub fn pps_delta(&self) -> Option<f64> {
+        Some(self.a.league()?.pps? - self.b.league()?.pps?)
+    }
+
+    /// Returns the difference in average VS (versus score) over the last 10 games.
+    ///
+    /// If either user has no VS, `None` is returned.
+    pub fn vs_delta(&self) -> Option<f64> {
+        Some(self.a.league()?.vs? - self.b.league()?.vs?)
+    }
+
+    /// Returns the chance (between 0 and 1) of the user A beating the user B,
+    /// estimated from both users' GLIXARE scores.
+    ///
+    /// If either user has no GLIXARE score, `None` is returned.
+    pub fn expected_score(&self) -> Option<f64> {
+        Some(expected_score(
+            self.a.league()?.gxe_opt()?,
+            self.b.league()?.gxe_opt()?,
+        ))
+    }
+}
+
+impl AsRef<UserComparison> for UserComparison {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// A difference between two snapshots of the same user,
+/// e.g. fetched before and after some games.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct UserDiff {
+    /// The previous snapshot.
+    pub prev: ComparedUser,
+    /// The current snapshot.
+    pub now: ComparedUser,
+}
+
+impl UserDiff {
+    /// Creates a new [`UserDiff`].
+    pub fn new(prev: ComparedUser, now: ComparedUser) -> Self {
+        Self { prev, now }
+    }
+
+    /// Returns the rank transition as `(previous rank, current rank)`.
+    ///
+    /// If the rank has not changed or either snapshot has no TETRA LEAGUE data,
+    /// `None` is returned.
+    ///
+    /// Use the comparison operators of [`Rank`] to see whether it is a promotion.
+    pub fn rank_changed(&self) -> Option<(Rank, Rank)> {
+        let prev = &self.prev.league()?.rank;
+        let now = &self.now.league()?.rank;
+        (prev != now).then(|| (prev.clone(), now.clone()))
+    }
+}
+
+impl AsRef<UserDiff> for UserDiff {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// A user in a [`UserComparison`] or a [`UserDiff`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ComparedUser {
+    /// The detailed information about the user.
+    pub user: User,
+    /// The user's TETRA LEAGUE data,
+    /// or `None` if the API returned an empty object (e.g. the user is banned).
+    pub league: Option<LeagueData>,
+}
+
+impl ComparedUser {
+    /// Creates a new [`ComparedUser`].
+    pub fn new(user: User, league: Option<LeagueData>) -> Self {
+        Self { user, league }
+    }
+
+    /// Returns the user's TETRA LEAGUE data.
+    fn league(&self) -> Option<&LeagueData> {
+        self.league.as_ref()
+    }
+}
+
+impl AsRef<ComparedUser> for ComparedUser {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn user(username: &str, xp: f64) -> User {
+        serde_json::from_value(json!({
+            "_id": "621db46d1d638ea850be2aa0",
+            "username": username,
+            "role": "user",
+            "badges": [],
+            "xp": xp,
+            "gamesplayed": 0,
+            "gameswon": 0,
+            "gametime": 0.0,
+            "supporter_tier": 0,
+            "connections": {},
+            "achievements": [],
+            "ar": 0,
+            "ar_counts": {}
+        }))
+        .unwrap()
+    }
+
+    fn league(tr: f64, gxe: f64, apm: Value, pps: Value, vs: Value) -> LeagueData {
+        league_with_rank(tr, gxe, apm, pps, vs, "a")
+    }
+
+    fn league_with_rank(
+        tr: f64,
+        gxe: f64,
+        apm: Value,
+        pps: Value,
+        vs: Value,
+        rank: &str,
+    ) -> LeagueData {
+        serde_json::from_value(json!({
+            "gamesplayed": 20,
+            "gameswon": 10,
+            "glicko": 1500.0,
+            "rd": 60.0,
+            "decaying": false,
+            "tr": tr,
+            "gxe": gxe,
+            "rank": rank,
+            "apm": apm,
+            "pps": pps,
+            "vs": vs,
+            "past": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn user_comparison_computes_deltas() {
+        let comparison = UserComparison::new(
+            ComparedUser::new(
+                user("a", 32768.),
+                Some(league(15000., 70., json!(60.), json!(2.5), json!(130.))),
+            ),
+            ComparedUser::new(
+                user("b", 4096.),
+                Some(league(12000., 50., json!(45.), json!(2.), json!(100.))),
+            ),
+        );
+        assert_eq!(comparison.xp_delta(), 28672.);
+        assert_eq!(comparison.level_delta(), 14);
+        assert_eq!(comparison.tr_delta(), Some(3000.));
+        assert_eq!(comparison.apm_delta(), Some(15.));
+        assert_eq!(comparison.pps_delta(), Some(0.5));
+        assert_eq!(comparison.vs_delta(), Some(30.));
+        assert!((comparison.expected_score().unwrap() - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn user_comparison_returns_none_if_league_data_is_missing() {
+        let comparison = UserComparison::new(
+            ComparedUser::new(
+                user("a", 0.),
+                Some(league(-1., -1., Value::Null, Value::Null, Value::Null)),
+            ),
+            ComparedUser::new(user("b", 0.), None),
+        );
+        assert_eq!(comparison.level_delta(), 0);
+        assert_eq!(comparison.tr_delta(), None);
+        assert_eq!(comparison.apm_delta(), None);
+        assert_eq!(comparison.pps_delta(), None);
+        assert_eq!(comparison.vs_delta(), None);
+        assert_eq!(comparison.expected_score(), None);
+    }
+
+    fn snapshot(rank: &str) -> ComparedUser {
+        ComparedUser::new(
+            user("a", 0.),
+            Some(league_with_rank(
+                10000.,
+                50.,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                rank,
+            )),
+        )
+    }
+
+    #[test]
+    fn user_diff_rank_changed_returns_transition() {
+        let diff = UserDiff::new(snapshot("b"), snapshot("b+"));
+        let (prev, now) = diff.rank_changed().unwrap();
+        assert_eq!((&prev, &now), (&Rank::B, &Rank::BPlus));
+        assert!(prev < now);
+    }
+
+    #[test]
+    fn user_diff_rank_changed_returns_none_if_unchanged() {
+        assert_eq!(
+            UserDiff::new(snapshot("b"), snapshot("b")).rank_changed(),
+            None
+        );
+        let no_league = ComparedUser::new(user("a", 0.), None);
+        assert_eq!(UserDiff::new(no_league, snapshot("b")).rank_changed(), None);
+    }
+}
@@ -4,6 +4,7 @@
 //! see the [API document](https://tetr.io/about/api/#labsleagueranks).
 
 use crate::model::prelude::*;
+use std::collections::BTreeMap;
 
 /// A view over all TETRA LEAGUE ranks and their metadata.
 #[derive(Clone, Debug, Deserialize)]
@@ -22,6 +23,15 @@ pub struct LabsLeagueRanks {
     pub data: LeagueRanksData,
 }
 
+impl LabsLeagueRanks {
+    /// Returns the data of the specified rank.
+    ///
+    /// If the rank is [`Rank::Z`] (unranked), `None` is returned.
+    pub fn get(&self, rank: &Rank) -> Option<&RankData> {
+        self.data.get(rank)
+    }
+}
+
 impl AsRef<LabsLeagueRanks> for LabsLeagueRanks {
     fn as_ref(&self) -> &Self {
         self
@@ -93,6 +103,62 @@ pub struct LeagueRanksData {
     pub rank_d: RankData,
 }
 
+impl LeagueRanksData {
+    /// Returns the pairs of the ranks and their data, from the highest rank.
+    pub fn ranks(&self) -> [(Rank, &RankData); 18] {
+        [
+            (Rank::XPlus, &self.rank_x_plus),
+            (Rank::X, &self.rank_x),
+            (Rank::U, &self.rank_u),
+            (Rank::SS, &self.rank_ss),
+            (Rank::SPlus, &self.rank_s_plus),
+            (Rank::S, &self.rank_s),
+            (Rank::SMinus, &self.rank_s_minus),
+            (Rank::APlus, &self.rank_a_plus),
+            (Rank::A, &self.rank_a),
+            (Rank::AMinus, &self.rank_a_minus),
+            (Rank::BPlus, &self.rank_b_plus),
+            (Rank::B, &self.rank_b),
+            (Rank::BMinus, &self.rank_b_minus),
+            (Rank::CPlus, &self.rank_c_plus),
+            (Rank::C, &self.rank_c),
+            (Rank::CMinus, &self.rank_c_minus),
+            (Rank::DPlus, &self.rank_d_plus),
+            (Rank::D, &self.rank_d),
+        ]
+    }
+
+    /// Returns an iterator over the pairs of the ranks and their data, from the lowest rank.
+    pub fn ranks_ascending(&self) -> impl Iterator<Item = (Rank, &RankData)> {
+        self.ranks().into_iter().rev()
+    }
+
+    /// Returns the data of the specified rank.
+    ///
+    /// If the rank is [`Rank::Z`] (unranked), `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // What TR is needed for S rank?
+    /// let tr = league_ranks_data.get(&Rank::S).map(|d| d.tr);
+    /// ```
+    pub fn get(&self, rank: &Rank) -> Option<&RankData> {
+        self.ranks()
+            .into_iter()
+            .find(|(r, _)| r == rank)
+            .map(|(_, data)| data)
+    }
+
+    /// Returns the amount of players per rank.
+    pub fn distribution(&self) -> BTreeMap<Rank, u32> {
+        self.ranks()
+            .into_iter()
+            .map(|(rank, data)| (rank, data.count))
+            .collect()
+    }
+}
+
 impl AsRef<LeagueRanksData> for LeagueRanksData {
     fn as_ref(&self) -> &Self {
         self
@@ -129,3 +195,65 @@ impl AsRef<RankData> for RankData {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Map, Value};
+
+    fn league_ranks_data() -> LeagueRanksData {
+        let ranks = [
+            "x+", "x", "u", "ss", "s+", "s", "s-", "a+", "a", "a-", "b+", "b", "b-", "c+", "c",
+            "c-", "d+", "d",
+        ];
+        let mut data = Map::new();
+        data.insert("total".to_string(), json!(171));
+        for (i, rank) in ranks.into_iter().enumerate() {
+            data.insert(
+                rank.to_string(),
+                json!({
+                    "pos": i,
+                    "percentile": 0.0,
+                    "tr": 25000 - i * 1000,
+                    "targettr": 0.0,
+                    "apm": null,
+                    "pps": null,
+                    "vs": null,
+                    "count": i + 1
+                }),
+            );
+        }
+        serde_json::from_value(Value::Object(data)).unwrap()
+    }
+
+    #[test]
+    fn league_ranks_data_distribution_tallies_counts_per_rank() {
+        let distribution = league_ranks_data().distribution();
+        assert_eq!(distribution.len(), 18);
+        assert_eq!(distribution[&Rank::XPlus], 1);
+        assert_eq!(distribution[&Rank::SS], 4);
+        assert_eq!(distribution[&Rank::D], 18);
+        assert_eq!(distribution.values().sum::<u32>(), 171);
+        assert_eq!(distribution.keys().next(), Some(&Rank::D));
+        assert!(!distribution.contains_key(&Rank::Z));
+    }
+
+    #[test]
+    fn league_ranks_data_get_returns_rank_data() {
+        let data = league_ranks_data();
+        assert_eq!(data.get(&Rank::XPlus).unwrap().tr, 25000.);
+        assert_eq!(data.get(&Rank::S).unwrap().tr, 20000.);
+        assert_eq!(data.get(&Rank::D).unwrap().count, 18);
+        assert!(data.get(&Rank::Z).is_none());
+    }
+
+    #[test]
+    fn league_ranks_data_ranks_ascending_starts_from_lowest() {
+        let data = league_ranks_data();
+        let ranks: Vec<Rank> = data.ranks_ascending().map(|(r, _)| r).collect();
+        assert_eq!(ranks.len(), 18);
+        assert_eq!(ranks.first(), Some(&Rank::D));
+        assert_eq!(ranks.last(), Some(&Rank::XPlus));
+        assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+    }
+}
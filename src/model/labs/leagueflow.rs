@@ -30,8 +30,241 @@ pub struct LabsLeagueflow {
     pub points: Vec<[i64; 4]>,
 }
 
+impl LabsLeagueflow {
+    /// Returns the series of the points as `(timestamp, TR)` pairs, oldest first.
+    ///
+    /// The timestamp is the true UNIX timestamp in milliseconds,
+    /// and the TR is the user's TR after the match.
+    pub fn series(&self) -> Vec<(i64, i64)> {
+        self.points
+            .iter()
+            .map(|[offset, _, tr, _]| (self.oldest_record_ts + offset, *tr))
+            .collect()
+    }
+
+    /// Decodes the points into the list of the matches, oldest first.
+    ///
+    /// The API document describes the points only briefly,
+    /// so this assumes the following:
+    ///
+    /// - The points are sorted from oldest to newest.
+    /// - The TR change of a match is the difference from the user's TR after the previous match.
+    ///   So it is `None` for the oldest match.
+    /// - The opponent's TR is the TR before the match, as is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let leagueflow = client
+    ///     .get_labs_leagueflow("rinrin-rs")
+    ///     .await?
+    ///     .data
+    ///     .expect("no data");
+    ///
+    /// for m in leagueflow.matches() {
+    ///     println!("{:?} {:?}", m.result, m.tr_change);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self) -> Vec<LeagueflowMatch> {
+        let mut prev_tr = None;
+        self.points
+            .iter()
+            .map(|&[offset, result, tr, opponent_tr]| {
+                let m = LeagueflowMatch {
+                    played_at: self.oldest_record_ts + offset,
+                    result: MatchResult::from_code(result),
+                    tr,
+                    opponent_tr,
+                    tr_change: prev_tr.map(|prev| tr - prev),
+                };
+                prev_tr = Some(tr);
+                m
+            })
+            .collect()
+    }
+}
+
 impl AsRef<LabsLeagueflow> for LabsLeagueflow {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+/// A TETRA LEAGUE match decoded from a point of the [`LabsLeagueflow`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LeagueflowMatch {
+    /// The UNIX timestamp in milliseconds when the match was played.
+    pub played_at: i64,
+    /// The result of the match.
+    pub result: MatchResult,
+    /// The user's TR after the match.
+    pub tr: i64,
+    /// The opponent's TR before the match.
+    /// If the opponent was unranked, this is the same as [`LeagueflowMatch::tr`].
+    pub opponent_tr: i64,
+    /// The user's TR change from the previous match.
+    /// `None` for the oldest match.
+    pub tr_change: Option<i64>,
+}
+
+impl AsRef<LeagueflowMatch> for LeagueflowMatch {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// A result of a TETRA LEAGUE match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MatchResult {
+    /// Victory.
+    Victory,
+    /// Defeat.
+    Defeat,
+    /// Victory by disqualification.
+    VictoryByDisqualification,
+    /// Defeat by disqualification.
+    DefeatByDisqualification,
+    /// Tie.
+    Tie,
+    /// No contest.
+    NoContest,
+    /// Match nullified.
+    Nullified,
+    /// An unknown result code.
+    Unknown(i64),
+}
+
+impl MatchResult {
+    /// Converts from a result code of the points.
+    fn from_code(code: i64) -> Self {
+        match code {
+            1 => Self::Victory,
+            2 => Self::Defeat,
+            3 => Self::VictoryByDisqualification,
+            4 => Self::DefeatByDisqualification,
+            5 => Self::Tie,
+            6 => Self::NoContest,
+            7 => Self::Nullified,
+            c => Self::Unknown(c),
+        }
+    }
+
+    /// Whether the user won the match, including by disqualification.
+    pub fn is_victory(&self) -> bool {
+        matches!(self, Self::Victory | Self::VictoryByDisqualification)
+    }
+
+    /// Whether the user lost the match, including by disqualification.
+    pub fn is_defeat(&self) -> bool {
+        matches!(self, Self::Defeat | Self::DefeatByDisqualification)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::response::Response;
+    use serde_json::json;
+
+    #[test]
+    fn leagueflow_series_adds_start_time_to_offsets() {
+        let leagueflow: LabsLeagueflow = serde_json::from_value(json!({
+            "startTime": 1700000000000i64,
+            "points": [[0, 1, 10000, 9000], [60000, 2, 9950, 11000], [180000, 1, 10020, 10500]]
+        }))
+        .unwrap();
+        let series = leagueflow.series();
+        assert_eq!(series.first(), Some(&(1700000000000, 10000)));
+        assert_eq!(series.last(), Some(&(1700000180000, 10020)));
+    }
+
+    #[test]
+    fn leagueflow_matches_decodes_points() {
+        let leagueflow: LabsLeagueflow = serde_json::from_value(json!({
+            "startTime": 1700000000000i64,
+            "points": [
+                [0, 1, 10000, 9000],
+                [60000, 2, 9950, 11000],
+                [120000, 4, 9900, 9800],
+                [180000, 9, 10020, 10020]
+            ]
+        }))
+        .unwrap();
+        let matches = leagueflow.matches();
+        assert_eq!(matches.len(), 4);
+        assert_eq!(
+            matches[0],
+            LeagueflowMatch {
+                played_at: 1700000000000,
+                result: MatchResult::Victory,
+                tr: 10000,
+                opponent_tr: 9000,
+                tr_change: None,
+            }
+        );
+        assert_eq!(matches[1].tr_change, Some(-50));
+        assert!(matches[1].result.is_defeat());
+        assert_eq!(matches[2].result, MatchResult::DefeatByDisqualification);
+        assert!(matches[2].result.is_defeat() && !matches[2].result.is_victory());
+        assert_eq!(matches[3].result, MatchResult::Unknown(9));
+        assert_eq!(matches[3].tr_change, Some(120));
+    }
+
+    /// A whole response of the endpoint, trimmed to a few points.
+    fn leagueflow_response() -> Response<LabsLeagueflow> {
+        serde_json::from_value(json!({
+            "success": true,
+            "cache": {
+                "status": "miss",
+                "cached_at": 1722556800000u64,
+                "cached_until": 1722557100000u64
+            },
+            "data": {
+                "startTime": 1722470412345i64,
+                "points": [
+                    [0, 1, 15874, 15321],
+                    [512043, 2, 15790, 16102],
+                    [1048302, 1, 15861, 15633],
+                    [86410233, 5, 15861, 15900],
+                    [86991045, 3, 15932, 15932]
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn leagueflow_matches_decodes_api_response() {
+        let matches = leagueflow_response().data.unwrap().matches();
+        assert_eq!(matches.len(), 5);
+        assert_eq!(
+            matches[0],
+            LeagueflowMatch {
+                played_at: 1722470412345,
+                result: MatchResult::Victory,
+                tr: 15874,
+                opponent_tr: 15321,
+                tr_change: None,
+            }
+        );
+        assert_eq!(matches[3].result, MatchResult::Tie);
+        assert_eq!(matches[3].tr_change, Some(0));
+        assert_eq!(matches[4].result, MatchResult::VictoryByDisqualification);
+        assert_eq!(matches[4].tr_change, Some(71));
+    }
+
+    #[test]
+    fn leagueflow_matches_returns_empty_if_no_points() {
+        let leagueflow: LabsLeagueflow =
+            serde_json::from_value(json!({ "startTime": 0, "points": [] })).unwrap();
+        assert!(leagueflow.matches().is_empty());
+    }
+}
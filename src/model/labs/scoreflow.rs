@@ -22,8 +22,83 @@ pub struct LabsScoreflow {
     pub points: Vec<[i64; 3]>,
 }
 
+impl LabsScoreflow {
+    /// Returns the series of the points as `(timestamp, score)` pairs, oldest first.
+    ///
+    /// The timestamp is the true UNIX timestamp in milliseconds,
+    /// and the score is as-is (for 40 LINES, this is negative).
+    pub fn series(&self) -> Vec<(i64, i64)> {
+        self.points
+            .iter()
+            .map(|[offset, _, score]| (self.oldest_record_ts + offset, *score))
+            .collect()
+    }
+
+    /// Returns the series of the Personal Best points as `(timestamp, score)` pairs, oldest first.
+    ///
+    /// See [`LabsScoreflow::series`] for the details of the values.
+    pub fn pb_series(&self) -> Vec<(i64, i64)> {
+        self.points
+            .iter()
+            .filter(|[_, pb, _]| *pb == 1)
+            .map(|[offset, _, score]| (self.oldest_record_ts + offset, *score))
+            .collect()
+    }
+}
+
 impl AsRef<LabsScoreflow> for LabsScoreflow {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::response::Response;
+    use serde_json::json;
+
+    /// A whole response of the endpoint, trimmed to a few points.
+    fn scoreflow() -> LabsScoreflow {
+        let rsp: Response<LabsScoreflow> = serde_json::from_value(json!({
+            "success": true,
+            "cache": {
+                "status": "miss",
+                "cached_at": 1722556800000u64,
+                "cached_until": 1722557100000u64
+            },
+            "data": {
+                "startTime": 1657299524000i64,
+                "points": [
+                    [0, 1, -95383],
+                    [7954931, 0, -98214],
+                    [22419452, 1, -82035],
+                    [9436458931i64, 1, -52321]
+                ]
+            }
+        }))
+        .unwrap();
+        rsp.data.unwrap()
+    }
+
+    #[test]
+    fn scoreflow_series_adds_start_time_to_offsets() {
+        let series = scoreflow().series();
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.first(), Some(&(1657299524000, -95383)));
+        assert_eq!(series.last(), Some(&(1666735982931, -52321)));
+    }
+
+    #[test]
+    fn scoreflow_pb_series_skips_non_pb_points() {
+        let series = scoreflow().pb_series();
+        assert_eq!(
+            series,
+            [
+                (1657299524000, -95383),
+                (1657321943452, -82035),
+                (1666735982931, -52321)
+            ]
+        );
+    }
+}
@@ -5,7 +5,10 @@
 //! - About the endpoint "Historical User Leaderboard",
 //!   see the [API document](https://tetr.io/about/api/#usershistoryleaderboardseason).
 
-use crate::model::{prelude::*, user::AchievementRatingCounts};
+use crate::{
+    model::{labs::league_ranks::LabsLeagueRanks, prelude::*, user::AchievementRatingCounts},
+    util::deserialize_string_or_f64,
+};
 
 /// An array of users. (user leaderboard)
 #[derive(Clone, Debug, Deserialize)]
@@ -15,6 +18,26 @@ pub struct Leaderboard {
     pub entries: Vec<LeaderboardUser>,
 }
 
+impl Leaderboard {
+    /// Returns the entry of the specified user.
+    ///
+    /// If the user is not in the entries, `None` is returned.
+    pub fn find_user(&self, user_id: &UserId) -> Option<&LeaderboardUser> {
+        self.entries.iter().find(|e| &e.id == user_id)
+    }
+
+    /// Returns the zero-based index of the specified user in the entries.
+    ///
+    /// Note that this is the index within the fetched page,
+    /// not the position in the whole leaderboard.
+    /// If the user is not in the entries, `None` is returned.
+    pub fn rank_of(&self, user_id: &UserId) -> Option<usize> {
+        self.entries.iter().position(|e| &e.id == user_id)
+    }
+}
+
+impl_for_entries!(Leaderboard, LeaderboardUser);
+
 impl AsRef<Leaderboard> for Leaderboard {
     fn as_ref(&self) -> &Self {
         self
@@ -38,6 +61,7 @@ pub struct LeaderboardUser {
     #[serde(rename = "ts")]
     pub created_at: Option<Timestamp>,
     /// The user's XP in points.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub xp: f64,
     /// The user's ISO 3166-1 country code, or `None` if hidden/unknown.
     /// Some vanity flags exist.
@@ -101,6 +125,7 @@ pub struct PartialLeagueData {
     #[serde(rename = "gameswon")]
     pub games_won: u32,
     /// This user's TR (Tetra Rating).
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub tr: f64,
     /// This user's GLIXARE.
     pub gxe: f64,
@@ -112,6 +137,7 @@ pub struct PartialLeagueData {
     #[serde(rename = "bestrank")]
     pub best_rank: Option<Rank>,
     /// This user's Glicko-2 rating.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub glicko: f64,
     /// This user's Glicko-2 Rating Deviation.
     ///
@@ -134,6 +160,33 @@ pub struct PartialLeagueData {
     pub is_decaying: bool,
 }
 
+impl PartialLeagueData {
+    /// Whether the user's rank is consistent with the user's TR under the current rank cutoffs.
+    ///
+    /// The expected rank is the highest rank whose required TR the user's TR reaches,
+    /// or the D rank if the user's TR is below all the requirements.
+    /// If the user is unranked (z), `true` is returned because there is no cutoff to check.
+    ///
+    /// This is useful to detect stale data.
+    ///
+    /// # Arguments
+    ///
+    /// - `ranks` - The current TETRA LEAGUE ranks,
+    ///   which can be got by [`Client::get_labs_league_ranks`](crate::client::Client::get_labs_league_ranks).
+    pub fn rank_matches_tr(&self, ranks: &LabsLeagueRanks) -> bool {
+        if self.rank == Rank::Z {
+            return true;
+        }
+        let expected = ranks
+            .data
+            .ranks()
+            .into_iter()
+            .find(|(_, data)| self.tr >= data.tr)
+            .map_or(Rank::D, |(rank, _)| rank);
+        self.rank == expected
+    }
+}
+
 impl AsRef<PartialLeagueData> for PartialLeagueData {
     fn as_ref(&self) -> &Self {
         self
@@ -148,6 +201,34 @@ pub struct HistoricalLeaderboard {
     pub entries: Vec<PastUserWithPrisecter>,
 }
 
+impl HistoricalLeaderboard {
+    /// Returns the champion of the season,
+    /// the entry in the first place of the season's global leaderboards.
+    ///
+    /// If there is no such entry, `None` is returned.
+    pub fn champion(&self) -> Option<&PastUserWithPrisecter> {
+        self.entries.iter().find(|e| e.placement == 1)
+    }
+
+    /// Returns the entry of the specified user.
+    ///
+    /// If the user is not in the entries, `None` is returned.
+    pub fn find_user(&self, user_id: &UserId) -> Option<&PastUserWithPrisecter> {
+        self.entries.iter().find(|e| &e.id == user_id)
+    }
+
+    /// Returns the zero-based index of the specified user in the entries.
+    ///
+    /// Note that this is the index within the fetched page,
+    /// not the user's placement in the season.
+    /// If the user is not in the entries, `None` is returned.
+    pub fn rank_of(&self, user_id: &UserId) -> Option<usize> {
+        self.entries.iter().position(|e| &e.id == user_id)
+    }
+}
+
+impl_for_entries!(HistoricalLeaderboard, PastUserWithPrisecter);
+
 impl AsRef<HistoricalLeaderboard> for HistoricalLeaderboard {
     fn as_ref(&self) -> &Self {
         self
@@ -180,10 +261,12 @@ pub struct PastUserWithPrisecter {
     #[serde(rename = "gameswon")]
     pub games_won: u32,
     /// This user's final Glicko-2 rating.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub glicko: f64,
     /// This user's final Glicko-2 Rating Deviation.
     pub rd: f64,
     /// This user's final TR (Tetra Rating).
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub tr: f64,
     /// This user's final GLIXARE score (a % chance of beating an average player).
     pub gxe: f64,
@@ -216,3 +299,195 @@ impl AsRef<PastUserWithPrisecter> for PastUserWithPrisecter {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn past_user(username: &str, placement: i32) -> Value {
+        json!({
+            "_id": "621db46d1d638ea850be2aa0",
+            "season": "1",
+            "username": username,
+            "country": "JP",
+            "placement": placement,
+            "ranked": true,
+            "gamesplayed": 100,
+            "gameswon": 80,
+            "glicko": 4000.0,
+            "rd": 60.0,
+            "tr": 25000.0,
+            "gxe": 99.0,
+            "rank": "x+",
+            "bestrank": "x+",
+            "apm": 180.0,
+            "pps": 3.5,
+            "vs": 400.0,
+            "p": { "pri": 25000.0, "sec": 0.0, "ter": 0.0 }
+        })
+    }
+
+    fn leaderboard_user(id: &str, username: &str) -> Value {
+        json!({
+            "_id": id,
+            "username": username,
+            "role": "user",
+            "xp": 0.0,
+            "league": {
+                "gamesplayed": 100,
+                "gameswon": 50,
+                "tr": 25000.0,
+                "gxe": 99.0,
+                "rank": "x+",
+                "glicko": 4000.0,
+                "decaying": false
+            },
+            "gamesplayed": 100,
+            "gameswon": 50,
+            "gametime": 3600.0,
+            "ar": 0,
+            "ar_counts": {},
+            "p": { "pri": 25000.0, "sec": 0.0, "ter": 0.0 }
+        })
+    }
+
+    fn user_id(id: &str) -> UserId {
+        serde_json::from_value(json!(id)).unwrap()
+    }
+
+    #[test]
+    fn leaderboard_finds_user_by_id() {
+        let leaderboard: Leaderboard = serde_json::from_value(json!({
+            "entries": [
+                leaderboard_user("5e32fc85ab319c2ab1beb07c", "czsmall0402"),
+                leaderboard_user("621db46d1d638ea850be2aa0", "rinrin-rs")
+            ]
+        }))
+        .unwrap();
+        let id = user_id("621db46d1d638ea850be2aa0");
+        assert_eq!(leaderboard.find_user(&id).unwrap().username, "rinrin-rs");
+        assert_eq!(leaderboard.rank_of(&id), Some(1));
+        let unknown = user_id("000000000000000000000000");
+        assert!(leaderboard.find_user(&unknown).is_none());
+        assert_eq!(leaderboard.rank_of(&unknown), None);
+    }
+
+    #[test]
+    fn historical_leaderboard_finds_user_by_id() {
+        let mut second = past_user("vincehd", 2);
+        second["_id"] = json!("5e32fc85ab319c2ab1beb07c");
+        let leaderboard: HistoricalLeaderboard = serde_json::from_value(json!({
+            "entries": [past_user("czsmall0402", 1), second]
+        }))
+        .unwrap();
+        let id = user_id("5e32fc85ab319c2ab1beb07c");
+        assert_eq!(leaderboard.find_user(&id).unwrap().username, "vincehd");
+        assert_eq!(leaderboard.rank_of(&id), Some(1));
+        assert_eq!(
+            leaderboard.rank_of(&user_id("000000000000000000000000")),
+            None
+        );
+    }
+
+    #[test]
+    fn historical_leaderboard_champion_returns_first_place() {
+        let leaderboard: HistoricalLeaderboard = serde_json::from_value(json!({
+            "entries": [past_user("czsmall0402", 1), past_user("vincehd", 2)]
+        }))
+        .unwrap();
+        assert_eq!(leaderboard.champion().unwrap().username, "czsmall0402");
+    }
+
+    #[test]
+    fn historical_leaderboard_iterates_entries() {
+        let leaderboard: HistoricalLeaderboard = serde_json::from_value(json!({
+            "entries": [past_user("czsmall0402", 1), past_user("vincehd", 2)]
+        }))
+        .unwrap();
+        let mut usernames = Vec::new();
+        for u in &leaderboard {
+            usernames.push(u.username.as_str());
+        }
+        assert_eq!(usernames, ["czsmall0402", "vincehd"]);
+        assert_eq!(leaderboard.as_slice().len(), 2);
+        assert_eq!(leaderboard.iter().count(), 2);
+        let placements: Vec<i32> = leaderboard.into_iter().map(|u| u.placement).collect();
+        assert_eq!(placements, [1, 2]);
+    }
+
+    #[test]
+    fn historical_leaderboard_champion_returns_none_if_no_first_place() {
+        let leaderboard: HistoricalLeaderboard = serde_json::from_value(json!({
+            "entries": [past_user("vincehd", 2)]
+        }))
+        .unwrap();
+        assert!(leaderboard.champion().is_none());
+        let leaderboard: HistoricalLeaderboard =
+            serde_json::from_value(json!({ "entries": [] })).unwrap();
+        assert!(leaderboard.champion().is_none());
+    }
+
+    fn league_ranks() -> LabsLeagueRanks {
+        let ranks = [
+            "x+", "x", "u", "ss", "s+", "s", "s-", "a+", "a", "a-", "b+", "b", "b-", "c+", "c",
+            "c-", "d+", "d",
+        ];
+        let mut data = serde_json::Map::new();
+        data.insert("total".to_string(), json!(18));
+        for (i, rank) in ranks.into_iter().enumerate() {
+            data.insert(
+                rank.to_string(),
+                json!({
+                    "pos": i,
+                    "percentile": 0.0,
+                    // 25000, 24000, ..., 8000
+                    "tr": 25000 - i * 1000,
+                    "targettr": 0.0,
+                    "apm": null,
+                    "pps": null,
+                    "vs": null,
+                    "count": 1
+                }),
+            );
+        }
+        serde_json::from_value(json!({
+            "_id": "leagueranks_1725000000000",
+            "s": "league_ranks",
+            "t": "2024-08-30T06:40:00.000Z",
+            "data": data
+        }))
+        .unwrap()
+    }
+
+    fn partial_league_data(tr: f64, rank: &str) -> PartialLeagueData {
+        serde_json::from_value(json!({
+            "gamesplayed": 100,
+            "gameswon": 50,
+            "tr": tr,
+            "gxe": 50.0,
+            "rank": rank,
+            "glicko": 1500.0,
+            "decaying": false
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn partial_league_data_rank_matches_tr_returns_true_if_consistent() {
+        let ranks = league_ranks();
+        assert!(partial_league_data(25000., "x+").rank_matches_tr(&ranks));
+        assert!(partial_league_data(22500., "ss").rank_matches_tr(&ranks));
+        assert!(partial_league_data(8000., "d").rank_matches_tr(&ranks));
+        assert!(partial_league_data(100., "d").rank_matches_tr(&ranks));
+        assert!(partial_league_data(-1., "z").rank_matches_tr(&ranks));
+    }
+
+    #[test]
+    fn partial_league_data_rank_matches_tr_returns_false_if_stale() {
+        let ranks = league_ranks();
+        assert!(!partial_league_data(22500., "x").rank_matches_tr(&ranks));
+        assert!(!partial_league_data(24999., "x+").rank_matches_tr(&ranks));
+        assert!(!partial_league_data(100., "d+").rank_matches_tr(&ranks));
+    }
+}
@@ -0,0 +1,49 @@
+/// A macro to implement the slice access and the iteration for `entries` field.
+///
+/// # Implementations
+///
+/// ```ignore
+/// pub fn as_slice(&self) -> &[T]
+/// pub fn iter(&self) -> std::slice::Iter<'_, T>
+/// impl IntoIterator for $name
+/// impl<'a> IntoIterator for &'a $name
+/// ```
+///
+/// # Dependencies
+///
+/// - `entries: Vec<T>` field
+///
+/// Go to [Vec]
+macro_rules! impl_for_entries {
+    ($name:ty, $entry:ty) => {
+        impl $name {
+            /// Returns the entries as a slice.
+            pub fn as_slice(&self) -> &[$entry] {
+                &self.entries
+            }
+
+            /// Returns an iterator over the entries.
+            pub fn iter(&self) -> std::slice::Iter<'_, $entry> {
+                self.entries.iter()
+            }
+        }
+
+        impl IntoIterator for $name {
+            type Item = $entry;
+            type IntoIter = std::vec::IntoIter<$entry>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.entries.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a $entry;
+            type IntoIter = std::slice::Iter<'a, $entry>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.entries.iter()
+            }
+        }
+    };
+}
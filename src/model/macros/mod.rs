@@ -13,6 +13,9 @@
 //! }
 //! ```
 
+/// Includes a macro to implement the methods and traits for `entries` field.
+#[macro_use]
+mod entries;
 /// Includes a macro to implement the method `get_user`.
 #[macro_use]
 mod get_user;
@@ -51,6 +54,7 @@ macro_rules! impl_for_username {
 ///
 /// ```ignore
 /// pub fn national_flag_url(&self) -> Option<String>
+/// pub fn country_name(&self) -> Option<&'static str>
 /// ```
 ///
 /// # Dependencies
@@ -68,6 +72,17 @@ macro_rules! impl_for_country {
                 .as_ref()
                 .map(|cc| format!("https://tetr.io/res/flags/{}.png", cc.to_lowercase()))
         }
+
+        /// Returns the English name of the user's country.
+        ///
+        /// If the user's country is hidden or unknown,
+        /// or it is not an ISO 3166-1 alpha-2 code (e.g. vanity flags),
+        /// `None` is returned.
+        pub fn country_name(&self) -> Option<&'static str> {
+            self.country
+                .as_deref()
+                .and_then(crate::util::country::country_name)
+        }
     };
 }
 
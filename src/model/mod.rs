@@ -5,12 +5,17 @@
 //! ```
 //! use tetr_ch::model::prelude::*;
 //! ```
+//!
+//! Some models implement [`PartialEq`] for use in assertions.
+//! Note that their float fields are compared as is,
+//! so a model containing `NaN` is never equal to itself.
 
 #[macro_use]
 mod macros;
 
 pub mod achievement_info;
 pub mod cache;
+pub mod comparison;
 pub mod labs;
 pub mod leaderboard;
 pub mod news;
@@ -15,6 +15,43 @@ pub struct NewsItems {
     pub news: Vec<News>,
 }
 
+impl NewsItems {
+    /// Returns the news items whose data matches the predicate.
+    ///
+    /// The `is_*` methods of [`NewsData`] can be passed as the predicate directly.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate` - The predicate to test the data of each news item.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    /// use tetr_ch::model::news::NewsData;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let news = client
+    ///     .get_news_latest(NewsStreamParam::Global, 100)
+    ///     .await
+    ///     .expect("request failed")
+    ///     .data
+    ///     .expect("no data");
+    ///
+    /// // Only the rank up news.
+    /// let rank_ups = news.filter_type(NewsData::is_rank_up_news);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_type<P>(&self, predicate: P) -> Vec<&News>
+    where
+        P: Fn(&NewsData) -> bool,
+    {
+        self.news.iter().filter(|n| predicate(&n.data)).collect()
+    }
+}
+
 impl AsRef<NewsItems> for NewsItems {
     fn as_ref(&self) -> &Self {
         self
@@ -41,6 +78,16 @@ pub struct News {
 
 impl News {
     impl_for_news_created_at!();
+
+    /// Returns the badge icon URL if the news is a badge news.
+    ///
+    /// If the news is not a badge news, `None` is returned.
+    pub fn badge_icon_url(&self) -> Option<String> {
+        match &self.data {
+            NewsData::BadgeNews(d) => Some(d.icon_url()),
+            _ => None,
+        }
+    }
 }
 
 impl AsRef<News> for News {
@@ -113,6 +160,33 @@ impl NewsData {
     pub fn is_unknown(&self) -> bool {
         matches!(self, Self::Unknown(_))
     }
+
+    /// Returns the username in the news data.
+    ///
+    /// If the news type has no username (e.g. unknown news types), `None` is returned.
+    pub fn username(&self) -> Option<&str> {
+        match self {
+            Self::LeaderboardNews(d) => Some(&d.username),
+            Self::PersonalBestNews(d) => Some(&d.username),
+            Self::BadgeNews(d) => Some(&d.username),
+            Self::RankUpNews(d) => Some(&d.username),
+            Self::SupporterNews(d) => Some(&d.username),
+            Self::SupporterGiftNews(d) => Some(&d.username),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the replay ID in the news data.
+    ///
+    /// Only the leaderboard news and the personal best news have a replay ID.
+    /// Otherwise, `None` is returned.
+    pub fn replay_id(&self) -> Option<&ReplayId> {
+        match self {
+            Self::LeaderboardNews(d) => Some(&d.replay_id),
+            Self::PersonalBestNews(d) => Some(&d.replay_id),
+            _ => None,
+        }
+    }
 }
 
 impl AsRef<NewsData> for NewsData {
@@ -255,3 +329,126 @@ impl SupporterGiftNews {
     impl_get_user!(username);
     impl_for_username!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn news(id: &str, r#type: &str, data: Value) -> Value {
+        json!({
+            "_id": id,
+            "stream": "user_621db46d1d638ea850be2aa0",
+            "type": r#type,
+            "data": data,
+            "ts": "2024-08-01T00:00:00.000Z"
+        })
+    }
+
+    fn mixed_news() -> NewsItems {
+        serde_json::from_value(json!({
+            "news": [
+                news("1", "personalbest", json!({
+                    "username": "rinrin-rs",
+                    "gametype": "40l",
+                    "result": 42000.0,
+                    "replayid": "6650f7f3d2a4f6f1e5a6b3c2"
+                })),
+                news("2", "rankup", json!({ "username": "rinrin-rs", "rank": "s" })),
+                news("3", "badge", json!({
+                    "username": "rinrin-rs",
+                    "type": "leaderboard1",
+                    "label": "Leaderboard #1"
+                })),
+                news("4", "rankup", json!({ "username": "rinrin-rs", "rank": "s+" })),
+                news("5", "mystery", json!({ "foo": 1 }))
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn news_items_filter_type_returns_matching_items() {
+        let items = mixed_news();
+        let ids = |v: Vec<&News>| v.iter().map(|n| n.id.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            ids(items.filter_type(NewsData::is_rank_up_news)),
+            ["2", "4"]
+        );
+        assert_eq!(
+            ids(items.filter_type(NewsData::is_personal_best_news)),
+            ["1"]
+        );
+        assert_eq!(ids(items.filter_type(NewsData::is_badge_news)), ["3"]);
+        assert_eq!(ids(items.filter_type(NewsData::is_unknown)), ["5"]);
+        assert!(items.filter_type(NewsData::is_leaderboard_news).is_empty());
+    }
+
+    #[test]
+    fn news_data_username_returns_username_of_each_variant() {
+        let data = |v: Value| serde_json::from_value::<NewsData>(v).unwrap();
+        let leaderboard = data(json!({
+            "username": "czsmall0402",
+            "gametype": "40l",
+            "rank": 1,
+            "result": 14000.0,
+            "replayid": "6650f7f3d2a4f6f1e5a6b3c2"
+        }));
+        assert!(leaderboard.is_leaderboard_news());
+        assert_eq!(leaderboard.username(), Some("czsmall0402"));
+        let supporter = data(json!({ "username": "vincehd" }));
+        assert!(supporter.is_supporter_news());
+        assert_eq!(supporter.username(), Some("vincehd"));
+        for n in mixed_news().news.iter().filter(|n| !n.data.is_unknown()) {
+            assert_eq!(n.data.username(), Some("rinrin-rs"));
+        }
+        assert!(data(json!({ "foo": 1 })).username().is_none());
+        let gift = NewsData::SupporterGiftNews(SupporterGiftNews {
+            username: "osk".to_string(),
+        });
+        assert_eq!(gift.username(), Some("osk"));
+    }
+
+    #[test]
+    fn news_data_replay_id_returns_replay_id_if_any() {
+        let items = mixed_news();
+        let replay_ids: Vec<_> = items
+            .news
+            .iter()
+            .map(|n| n.data.replay_id().map(|r| r.to_string()))
+            .collect();
+        assert_eq!(
+            replay_ids,
+            [
+                Some("6650f7f3d2a4f6f1e5a6b3c2".to_string()),
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn news_items_filter_type_accepts_closures() {
+        let items = mixed_news();
+        let filtered = items.filter_type(|d| !d.is_unknown());
+        assert_eq!(filtered.len(), 4);
+    }
+
+    #[test]
+    fn news_badge_icon_url_returns_url_of_badge_news_only() {
+        let items = mixed_news();
+        let urls: Vec<_> = items.news.iter().map(|n| n.badge_icon_url()).collect();
+        assert_eq!(
+            urls,
+            [
+                None,
+                None,
+                Some("https://tetr.io/res/badges/leaderboard1.png".to_string()),
+                None,
+                None
+            ]
+        );
+    }
+}
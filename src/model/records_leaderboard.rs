@@ -13,6 +13,8 @@ pub struct RecordsLeaderboard {
     pub entries: Vec<Record>,
 }
 
+impl_for_entries!(RecordsLeaderboard, Record);
+
 impl AsRef<RecordsLeaderboard> for RecordsLeaderboard {
     fn as_ref(&self) -> &Self {
         self
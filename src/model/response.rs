@@ -1,11 +1,15 @@
 //! Models for responses.
 
 use super::cache::CacheData;
-use crate::model::prelude::*;
+use crate::{
+    client::error::{ResponseError, RspErr},
+    model::prelude::*,
+};
+use serde::Serialize;
 use std::fmt;
 
 /// A struct for responses.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[non_exhaustive]
 pub struct Response<T: Clone + fmt::Debug + AsRef<T>> {
     /// Whether the request was successful.
@@ -17,6 +21,130 @@ pub struct Response<T: Clone + fmt::Debug + AsRef<T>> {
     pub cache: Option<CacheData>,
     /// The requested data.
     pub data: Option<T>,
+    /// The `ETag` header of the response, if the API returned one.
+    ///
+    /// This is not a part of the response body.
+    /// It is used to revalidate the response (e.g. by [`Client::revalidate_user`](crate::client::Client::revalidate_user)).
+    #[serde(skip)]
+    pub etag: Option<String>,
+}
+
+impl<T: Clone + fmt::Debug + AsRef<T>> Response<T> {
+    /// Splits the response into the requested data, the error, and the cache data,
+    /// consuming the `self` value.
+    ///
+    /// This is useful to handle the response in one `match` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let response = client.get_user("rinrin-rs").await.expect("request failed");
+    ///
+    /// match response.split() {
+    ///     (Some(user), _, _) => println!("XP: {}", user.xp),
+    ///     (None, Some(err), _) => eprintln!("Error: {:?}", err.msg),
+    ///     (None, None, _) => unreachable!(),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(self) -> (Option<T>, Option<ErrorResponse>, Option<CacheData>) {
+        (self.data, self.error, self.cache)
+    }
+
+    /// Returns a reference to the requested data, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let response = client.get_user("rinrin-rs").await.expect("request failed");
+    ///
+    /// if let Some(user) = response.data_ref() {
+    ///     println!("XP: {}", user.xp);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn data_ref(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// Maps the requested data with the given function, consuming the `self` value.
+    ///
+    /// The success flag, the error, and the cache data are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let response = client.get_user("rinrin-rs").await.expect("request failed");
+    ///
+    /// let role = response.map_data(|user| user.role);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_data<U, F>(self, f: F) -> Response<U>
+    where
+        U: Clone + fmt::Debug + AsRef<U>,
+        F: FnOnce(T) -> U,
+    {
+        Response {
+            is_success: self.is_success,
+            error: self.error,
+            cache: self.cache,
+            data: self.data.map(f),
+            etag: self.etag,
+        }
+    }
+
+    /// Returns the requested data or the error, consuming the `self` value.
+    ///
+    /// If there is neither data nor an error, an empty [`ErrorResponse`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tetr_ch::prelude::*;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = Client::new();
+    /// let response = client.get_user("rinrin-rs").await.expect("request failed");
+    ///
+    /// match response.ok_or_error() {
+    ///     Ok(user) => println!("XP: {}", user.xp),
+    ///     Err(err) => eprintln!("Error: {:?}", err.msg),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ok_or_error(self) -> Result<T, ErrorResponse> {
+        match self.data {
+            Some(data) => Ok(data),
+            None => Err(self.error.unwrap_or(ErrorResponse {
+                msg: None,
+                key: None,
+                context: None,
+            })),
+        }
+    }
+
+    /// Returns the requested data, consuming the `self` value.
+    ///
+    /// If there is no data, a [`ResponseError::ApiErr`] is returned.
+    pub(crate) fn into_data(self) -> RspErr<T> {
+        self.ok_or_error().map_err(ResponseError::ApiErr)
+    }
 }
 
 impl<T: Clone + fmt::Debug + AsRef<T>> AsRef<Response<T>> for Response<T> {
@@ -26,7 +154,9 @@ impl<T: Clone + fmt::Debug + AsRef<T>> AsRef<Response<T>> for Response<T> {
 }
 
 /// An error response.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// This can be serialized, e.g. to log or persist the failures.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct ErrorResponse {
     /// The error message.
@@ -42,3 +172,104 @@ impl AsRef<ErrorResponse> for ErrorResponse {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn split_returns_data_on_success() {
+        let rsp: Response<ErrorResponse> = serde_json::from_value(json!({
+            "success": true,
+            "cache": {
+                "status": "miss",
+                "cached_at": 1661710769000u64,
+                "cached_until": 1661710844000u64
+            },
+            "data": { "msg": "ok" }
+        }))
+        .unwrap();
+        let (data, error, cache) = rsp.split();
+        assert_eq!(data.unwrap().msg.as_deref(), Some("ok"));
+        assert!(error.is_none());
+        assert_eq!(cache.unwrap().cached_at, 1661710769000);
+    }
+
+    #[test]
+    fn split_returns_error_on_failure() {
+        let rsp: Response<ErrorResponse> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        let (data, error, cache) = rsp.split();
+        assert!(data.is_none());
+        assert_eq!(error.unwrap().msg.as_deref(), Some("No such user!"));
+        assert!(cache.is_none());
+    }
+
+    #[test]
+    fn combinators_on_success() {
+        let rsp: Response<ErrorResponse> = serde_json::from_value(json!({
+            "success": true,
+            "cache": {
+                "status": "miss",
+                "cached_at": 1661710769000u64,
+                "cached_until": 1661710844000u64
+            },
+            "data": { "msg": "ok" }
+        }))
+        .unwrap();
+        assert_eq!(rsp.data_ref().unwrap().msg.as_deref(), Some("ok"));
+        let mapped = rsp.map_data(|d| ErrorResponse {
+            msg: d.msg.map(|m| m.to_uppercase()),
+            key: None,
+            context: None,
+        });
+        assert!(mapped.is_success);
+        assert!(mapped.cache.is_some());
+        assert_eq!(mapped.ok_or_error().unwrap().msg.as_deref(), Some("OK"));
+    }
+
+    #[test]
+    fn combinators_on_failure() {
+        let rsp: Response<ErrorResponse> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        assert!(rsp.data_ref().is_none());
+        let mapped = rsp.map_data(|_| unreachable!() as ErrorResponse);
+        assert!(!mapped.is_success);
+        let err = mapped.ok_or_error().unwrap_err();
+        assert_eq!(err.msg.as_deref(), Some("No such user!"));
+    }
+
+    #[test]
+    fn ok_or_error_without_error_returns_empty_error() {
+        let rsp: Response<ErrorResponse> =
+            serde_json::from_value(json!({ "success": false })).unwrap();
+        let err = rsp.ok_or_error().unwrap_err();
+        assert!(err.msg.is_none() && err.key.is_none() && err.context.is_none());
+    }
+
+    #[test]
+    fn error_response_round_trips() {
+        let json = json!({
+            "msg": "No such user! | Either you mistyped something, or the account no longer exists.",
+            "key": "nouser",
+            "context": null
+        });
+        let err: ErrorResponse = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&err).unwrap(), json);
+        let rsp: Response<ErrorResponse> = serde_json::from_value(json!({
+            "success": false,
+            "error": json
+        }))
+        .unwrap();
+        let err = rsp.error.unwrap();
+        assert_eq!(err.key.as_deref(), Some("nouser"));
+        assert_eq!(serde_json::to_value(&err).unwrap(), json);
+    }
+}
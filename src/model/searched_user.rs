@@ -3,7 +3,7 @@
 //! About the endpoint "User Search",
 //! see the [API document](https://tetr.io/about/api/#userssearchquery).
 
-use crate::model::prelude::*;
+use crate::model::{prelude::*, response::Response};
 
 /// A searched user.
 ///
@@ -17,6 +17,36 @@ pub struct UserData {
     pub user: Option<UserInfo>,
 }
 
+impl UserData {
+    /// Whether a user was found.
+    pub fn found(&self) -> bool {
+        self.user.is_some()
+    }
+
+    /// Returns the found user's internal ID.
+    ///
+    /// If no user was found, `None` is returned.
+    pub fn user_id(&self) -> Option<&UserId> {
+        self.user.as_ref().map(|u| &u.id)
+    }
+}
+
+impl Response<UserData> {
+    /// Whether a user was found.
+    ///
+    /// If the request was unsuccessful, `false` is returned.
+    pub fn found(&self) -> bool {
+        self.data.as_ref().is_some_and(|d| d.found())
+    }
+
+    /// Returns the found user's internal ID.
+    ///
+    /// If the request was unsuccessful or no user was found, `None` is returned.
+    pub fn user_id(&self) -> Option<&UserId> {
+        self.data.as_ref()?.user_id()
+    }
+}
+
 impl AsRef<UserData> for UserData {
     fn as_ref(&self) -> &Self {
         self
@@ -44,3 +74,43 @@ impl AsRef<UserInfo> for UserInfo {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn user_id_returns_id_if_found() {
+        let rsp: Response<UserData> = serde_json::from_value(json!({
+            "success": true,
+            "data": {
+                "user": { "_id": "621db46d1d638ea850be2aa0", "username": "rinrin-rs" }
+            }
+        }))
+        .unwrap();
+        assert!(rsp.found());
+        assert_eq!(
+            rsp.user_id().unwrap().to_string(),
+            "621db46d1d638ea850be2aa0"
+        );
+    }
+
+    #[test]
+    fn user_id_returns_none_if_not_found() {
+        let rsp: Response<UserData> = serde_json::from_value(json!({
+            "success": true,
+            "data": { "user": null }
+        }))
+        .unwrap();
+        assert!(!rsp.found());
+        assert!(rsp.user_id().is_none());
+        let rsp: Response<UserData> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        assert!(!rsp.found());
+        assert!(rsp.user_id().is_none());
+    }
+}
@@ -27,11 +27,8 @@ impl ServerActivity {
     /// If several points are equally maximum, the first one is returned.
     /// If the activity is empty, `None` is returned.
     pub fn peak_index(&self) -> Option<usize> {
-        self.activity
-            .iter()
-            .enumerate()
-            .max_by_key(|(_, &v)| v)
-            .map(|(i, _)| i)
+        let peak = self.peak()?;
+        self.activity.iter().position(|&v| v == peak)
     }
 
     /// Returns the trough point of the activity.
@@ -53,6 +50,13 @@ impl ServerActivity {
             .map(|(i, _)| i)
     }
 
+    /// Returns the latest point of the activity.
+    ///
+    /// If the activity is empty, `None` is returned.
+    pub fn latest(&self) -> Option<u32> {
+        self.activity.first().copied()
+    }
+
     /// Returns the average of the activity.
     ///
     /// If the activity is empty, `None` is returned.
@@ -71,3 +75,32 @@ impl AsRef<ServerActivity> for ServerActivity {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_activity_analyzes_points() {
+        let activity = ServerActivity {
+            activity: vec![120, 300, 80, 300, 200],
+        };
+        assert_eq!(activity.latest(), Some(120));
+        assert_eq!(activity.peak(), Some(300));
+        assert_eq!(activity.peak_index(), Some(1));
+        assert_eq!(activity.trough(), Some(80));
+        assert_eq!(activity.trough_index(), Some(2));
+        assert_eq!(activity.average(), Some(200.));
+    }
+
+    #[test]
+    fn server_activity_returns_none_if_empty() {
+        let activity = ServerActivity { activity: vec![] };
+        assert_eq!(activity.latest(), None);
+        assert_eq!(activity.peak(), None);
+        assert_eq!(activity.peak_index(), None);
+        assert_eq!(activity.trough(), None);
+        assert_eq!(activity.trough_index(), None);
+        assert_eq!(activity.average(), None);
+    }
+}
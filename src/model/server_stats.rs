@@ -6,7 +6,7 @@
 use crate::model::prelude::*;
 
 /// Server Statistics about the TETR.IO.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[non_exhaustive]
 pub struct ServerStats {
     /// The amount of users on the server,
@@ -14,7 +14,9 @@ pub struct ServerStats {
     #[serde(rename = "usercount")]
     pub user_count: u64,
     /// The amount of users created a second
-    /// (through the last minute).
+    /// (averaged over the last minute).
+    ///
+    /// For the amount per minute, see [`ServerStats::users_per_minute`].
     #[serde(rename = "usercount_delta")]
     pub user_count_delta: f64,
     /// The amount of anonymous accounts on the server.
@@ -36,7 +38,9 @@ pub struct ServerStats {
     #[serde(rename = "gamesplayed")]
     pub games_play_count: u64,
     /// The amount of games played a second
-    /// (through the last minute).
+    /// (averaged over the last minute).
+    ///
+    /// For the amount per minute, see [`ServerStats::games_per_minute`].
     #[serde(rename = "gamesplayed_delta")]
     pub games_play_count_delta: f64,
     /// The amount of games played across all users,
@@ -93,6 +97,18 @@ impl ServerStats {
         self.play_time / 31536000.0
     }
 
+    /// Returns the amount of users created a minute
+    /// (averaged over the last minute).
+    pub fn users_per_minute(&self) -> f64 {
+        self.user_count_delta * 60.
+    }
+
+    /// Returns the amount of games played a minute
+    /// (averaged over the last minute).
+    pub fn games_per_minute(&self) -> f64 {
+        self.games_play_count_delta * 60.
+    }
+
     /// Returns the average amount of pieces placed per second.
     pub fn avg_pieces_per_second(&self) -> f64 {
         self.pieces_place_count as f64 / self.play_time
@@ -109,3 +125,46 @@ impl AsRef<ServerStats> for ServerStats {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn server_stats_json() -> serde_json::Value {
+        json!({
+            "usercount": 2826851,
+            "usercount_delta": 0.05,
+            "anoncount": 2187013,
+            "totalaccounts": 5834566,
+            "rankedcount": 68329,
+            "recordcount": 231924370,
+            "gamesplayed": 360412488,
+            "gamesplayed_delta": 12.4,
+            "gamesfinished": 197426339,
+            "gametime": 1593817064.2,
+            "inputs": 79478425234u64,
+            "piecesplaced": 10129488329u64
+        })
+    }
+
+    #[test]
+    fn server_stats_deserialized_from_same_json_are_equal() {
+        let json = server_stats_json();
+        let a: ServerStats = serde_json::from_value(json.clone()).unwrap();
+        let b: ServerStats = serde_json::from_value(json).unwrap();
+        assert_eq!(a, b);
+        let c = ServerStats {
+            inputs: a.inputs + 1,
+            ..a.clone()
+        };
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn server_stats_per_minute_multiplies_deltas_by_60() {
+        let stats: ServerStats = serde_json::from_value(server_stats_json()).unwrap();
+        assert!((stats.users_per_minute() - 3.).abs() < 1e-9);
+        assert!((stats.games_per_minute() - 744.).abs() < 1e-9);
+    }
+}
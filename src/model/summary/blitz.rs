@@ -3,6 +3,7 @@
 //! About the endpoint "User Summary: BLITZ",
 //! see the [API document](https://tetr.io/about/api/#usersusersummariesblitz).
 
+use super::final_stat;
 use crate::model::prelude::*;
 
 /// A struct that describes a summary of a user's BLITZ games.
@@ -12,13 +13,52 @@ pub struct Blitz {
     /// The user's BLITZ record, or `None` if never played.
     pub record: Option<Record>,
     /// The user's rank in global leaderboards, or -1 if not in global leaderboards.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank: i32,
     /// The user's rank in their country's leaderboards, or -1 if not in any.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank_local: i32,
 }
 
+impl Blitz {
+    /// Returns the user's best score.
+    ///
+    /// If the user has never played BLITZ, `None` is returned.
+    pub fn best_score(&self) -> Option<u64> {
+        final_stat(self.record.as_ref()?, "score")?.as_u64()
+    }
+}
+
 impl AsRef<Blitz> for Blitz {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::summary::record_json;
+    use serde_json::{json, Value};
+
+    fn blitz(record: Option<Value>) -> Blitz {
+        serde_json::from_value(json!({
+            "record": record,
+            "rank": -1,
+            "rank_local": -1
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn blitz_best_score_returns_final_score_if_played() {
+        let summary = blitz(Some(record_json("blitz", json!({ "score": 123456 }))));
+        assert_eq!(summary.best_score(), Some(123456));
+    }
+
+    #[test]
+    fn blitz_best_score_returns_none_if_never_played() {
+        let summary = blitz(None);
+        assert_eq!(summary.best_score(), None);
+    }
+}
@@ -3,6 +3,7 @@
 //! About the endpoint "User Summary: 40 LINES",
 //! see the [API document](https://tetr.io/about/api/#usersusersummaries40l).
 
+use super::final_stat;
 use crate::model::prelude::*;
 
 /// A struct that describes a summary of a user's 40 LINES games.
@@ -12,13 +13,61 @@ pub struct FortyLines {
     /// The user's 40 LINES record, or `None` if never played.
     pub record: Option<Record>,
     /// The user's rank in global leaderboards, or -1 if not in global leaderboards.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank: i32,
     /// The user's rank in their country's leaderboards, or -1 if not in any.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank_local: i32,
 }
 
+impl FortyLines {
+    /// Returns the user's best time in milliseconds.
+    ///
+    /// If the user has never played 40 LINES, `None` is returned.
+    pub fn best_time_ms(&self) -> Option<f64> {
+        final_stat(self.record.as_ref()?, "finaltime")?.as_f64()
+    }
+
+    /// Returns the user's best time in seconds.
+    ///
+    /// If the user has never played 40 LINES, `None` is returned.
+    pub fn best_time_secs(&self) -> Option<f64> {
+        self.best_time_ms().map(|ms| ms / 1000.)
+    }
+}
+
 impl AsRef<FortyLines> for FortyLines {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::summary::record_json;
+    use serde_json::{json, Value};
+
+    fn forty_lines(record: Option<Value>) -> FortyLines {
+        serde_json::from_value(json!({
+            "record": record,
+            "rank": -1,
+            "rank_local": -1
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn forty_lines_best_time_returns_final_time_if_played() {
+        let summary = forty_lines(Some(record_json("40l", json!({ "finaltime": 52321.5 }))));
+        assert_eq!(summary.best_time_ms(), Some(52321.5));
+        assert_eq!(summary.best_time_secs(), Some(52.3215));
+    }
+
+    #[test]
+    fn forty_lines_best_time_returns_none_if_never_played() {
+        let summary = forty_lines(None);
+        assert_eq!(summary.best_time_ms(), None);
+        assert_eq!(summary.best_time_secs(), None);
+    }
+}
@@ -3,7 +3,7 @@
 //! About the endpoint "User Summary: TETRA LEAGUE",
 //! see the [API document](https://tetr.io/about/api/#usersusersummariesleague).
 
-use crate::model::prelude::*;
+use crate::{model::prelude::*, util::deserialize_string_or_f64};
 use std::collections::HashMap;
 
 /// A league data wrapper.
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 /// The [`LeagueDataWrap`] struct is wrapped in this enum.
 /// Because the API returns an empty object when the user is banned.  
 /// For more information, see the [GitHub issue #107](https://github.com/Rinrin0413/tetr-ch-rs/issues/107).
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum LeagueDataWrap {
@@ -85,7 +85,7 @@ impl Default for LeagueDataWrap {
 ///
 /// Season information is only saved if the user had finished placements in the season,
 /// and was not banned or hidden.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[non_exhaustive]
 pub struct LeagueData {
     /// The amount of TETRA LEAGUE games played by this user.
@@ -95,6 +95,7 @@ pub struct LeagueData {
     #[serde(rename = "gameswon")]
     pub games_won: u32,
     /// This user's Glicko-2 rating, or -1 if less than 10 games were played.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub glicko: f64,
     /// This user's Glicko-2 Rating Deviation,or -1 if less than 10 games were played.
     /// If over 100, this user is unranked.
@@ -103,6 +104,7 @@ pub struct LeagueData {
     #[serde(rename = "decaying")]
     pub is_decaying: bool,
     /// This user's TR (Tetra Rating), or -1 if less than 10 games were played.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub tr: f64,
     /// This user's GLIXARE score (a % chance of beating an average player),
     /// or -1 if less than 10 games were played.
@@ -143,6 +145,56 @@ pub struct LeagueData {
 }
 
 impl LeagueData {
+    /// Returns the user's TR (Tetra Rating).
+    ///
+    /// If less than 10 games were played, `None` is returned.
+    pub fn rating_opt(&self) -> Option<f64> {
+        (self.tr != -1.).then_some(self.tr)
+    }
+
+    /// Returns the user's Glicko-2 rating.
+    ///
+    /// If less than 10 games were played, `None` is returned.
+    pub fn glicko_opt(&self) -> Option<f64> {
+        (self.glicko != -1.).then_some(self.glicko)
+    }
+
+    /// Returns the user's GLIXARE score.
+    ///
+    /// If less than 10 games were played, `None` is returned.
+    pub fn gxe_opt(&self) -> Option<f64> {
+        (self.gxe != -1.).then_some(self.gxe)
+    }
+
+    /// Whether the user is ranked.
+    ///
+    /// The user is ranked if the rank is not [`Rank::Z`].
+    /// A user whose RD is over 100 is unranked even if they have a TR.
+    pub fn is_ranked(&self) -> bool {
+        self.rank != Rank::Z
+    }
+
+    /// Whether the user is inactive (has not played in the last week).
+    ///
+    /// This is the same as the [`LeagueData::is_decaying`] field.
+    pub fn is_inactive(&self) -> bool {
+        self.is_decaying
+    }
+
+    /// Returns the user's position in global leaderboards.
+    ///
+    /// If not applicable, `None` is returned.
+    pub fn standing_opt(&self) -> Option<i32> {
+        self.standing.filter(|&s| s != -1)
+    }
+
+    /// Returns the user's position in local leaderboards.
+    ///
+    /// If not applicable, `None` is returned.
+    pub fn standing_local_opt(&self) -> Option<i32> {
+        self.standing_local.filter(|&s| s != -1)
+    }
+
     /// Returns the user's progress percentage in the rank.
     ///
     /// But there are cases where values less than 0 or greater than 100 are returned,
@@ -165,6 +217,28 @@ impl LeagueData {
         }
         None
     }
+
+    /// Returns the user's final placement information in the specified past season.
+    ///
+    /// If the user did not play in the season, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `season_id` - The season ID. (e.g. `"1"`)
+    pub fn season(&self, season_id: &str) -> Option<&PastUser> {
+        self.past.get(season_id)
+    }
+
+    /// Returns the user's final placement information in the latest past season they played.
+    ///
+    /// The season IDs are compared as numbers if possible.
+    /// If the user has no past seasons, `None` is returned.
+    pub fn latest_season(&self) -> Option<&PastUser> {
+        self.past
+            .iter()
+            .max_by_key(|(id, _)| (id.parse::<u64>().ok(), id.as_str()))
+            .map(|(_, past)| past)
+    }
 }
 
 impl AsRef<LeagueData> for LeagueData {
@@ -174,7 +248,7 @@ impl AsRef<LeagueData> for LeagueData {
 }
 
 /// Past season final placement information of a user.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[non_exhaustive]
 pub struct PastUser {
     /// The season ID.
@@ -195,10 +269,12 @@ pub struct PastUser {
     #[serde(rename = "gameswon")]
     pub games_won: u32,
     /// This user's final Glicko-2 rating.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub glicko: f64,
     /// This user's final Glicko-2 Rating Deviation.
     pub rd: f64,
     /// This user's final TR (Tetra Rating).
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub tr: f64,
     /// This user's final GLIXARE score (a % chance of beating an average player).
     pub gxe: f64,
@@ -217,6 +293,20 @@ pub struct PastUser {
 
 impl PastUser {
     impl_for_country!();
+
+    /// Returns the user's final position in the season's global leaderboards.
+    ///
+    /// If not applicable, `None` is returned.
+    pub fn placement_opt(&self) -> Option<i32> {
+        self.placement.filter(|&p| p != -1)
+    }
+
+    /// Returns the ratio of the games won to the games played in the season (0 to 1).
+    ///
+    /// If no games were played, `None` is returned.
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.games_played != 0).then(|| self.games_won as f64 / self.games_played as f64)
+    }
 }
 
 impl AsRef<PastUser> for PastUser {
@@ -224,3 +314,154 @@ impl AsRef<PastUser> for PastUser {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn league_data(tr: f64, standing: i32) -> LeagueData {
+        serde_json::from_value(json!({
+            "gamesplayed": 5,
+            "gameswon": 2,
+            "glicko": tr,
+            "rd": null,
+            "decaying": false,
+            "tr": tr,
+            "gxe": tr,
+            "rank": "z",
+            "standing": standing,
+            "standing_local": standing,
+            "past": {}
+        }))
+        .unwrap()
+    }
+
+    fn past_user(season: &str, placement: i32, is_ranked: bool) -> serde_json::Value {
+        json!({
+            "season": season,
+            "username": "rinrin-rs",
+            "country": "JP",
+            "placement": placement,
+            "ranked": is_ranked,
+            "gamesplayed": 40,
+            "gameswon": 30,
+            "glicko": 2000.0,
+            "rd": 60.0,
+            "tr": 20000.0,
+            "gxe": 75.0,
+            "rank": "s",
+            "bestrank": "s+",
+            "apm": 40.0,
+            "pps": 1.5,
+            "vs": 80.0
+        })
+    }
+
+    #[test]
+    fn league_data_looks_up_past_seasons() {
+        let mut data = league_data(15200.5, 42);
+        assert!(data.latest_season().is_none());
+        data.past = serde_json::from_value(json!({
+            "2": past_user("2", 1200, true),
+            "10": past_user("10", 800, true)
+        }))
+        .unwrap();
+        assert_eq!(data.season("2").unwrap().placement, Some(1200));
+        assert!(data.season("3").is_none());
+        assert_eq!(data.latest_season().unwrap().season, "10");
+    }
+
+    #[test]
+    fn past_user_helpers_for_ranked_season() {
+        let past: PastUser = serde_json::from_value(past_user("1", 1200, true)).unwrap();
+        assert_eq!(past.placement_opt(), Some(1200));
+        assert_eq!(past.win_rate(), Some(0.75));
+    }
+
+    #[test]
+    fn past_user_helpers_for_unranked_season() {
+        let mut json = past_user("1", -1, false);
+        json["gamesplayed"] = json!(0);
+        json["gameswon"] = json!(0);
+        let past: PastUser = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(past.placement_opt(), None);
+        assert_eq!(past.win_rate(), None);
+        json["placement"] = serde_json::Value::Null;
+        let past: PastUser = serde_json::from_value(json).unwrap();
+        assert_eq!(past.placement_opt(), None);
+    }
+
+    #[test]
+    fn league_data_deserializes_rating_from_string() {
+        let data: LeagueData = serde_json::from_value(json!({
+            "gamesplayed": 5,
+            "gameswon": 2,
+            "glicko": "1500.25",
+            "rd": null,
+            "decaying": false,
+            "tr": "15200.5",
+            "gxe": 50.0,
+            "rank": "z",
+            "past": {}
+        }))
+        .unwrap();
+        assert_eq!(data.tr, 15200.5);
+        assert_eq!(data.glicko, 1500.25);
+        let data = league_data(15200.5, 1);
+        assert_eq!(data.tr, 15200.5);
+    }
+
+    #[test]
+    fn league_data_is_ranked() {
+        let mut data = league_data(15200.5, 42);
+        assert!(!data.is_ranked());
+        data.games_played = 10;
+        data.rank = Rank::S;
+        assert!(data.is_ranked());
+        assert!(!data.is_inactive());
+    }
+
+    #[test]
+    fn league_data_is_unranked_if_rd_is_over_100() {
+        let mut data = league_data(15200.5, 42);
+        data.games_played = 100;
+        data.rd = Some(120.5);
+        assert_eq!(data.rank, Rank::Z);
+        assert_eq!(data.rating_opt(), Some(15200.5));
+        assert!(!data.is_ranked());
+    }
+
+    #[test]
+    fn league_data_is_inactive_if_decaying() {
+        let mut data = league_data(15200.5, 42);
+        data.games_played = 100;
+        data.rank = Rank::S;
+        data.is_decaying = true;
+        assert!(data.is_inactive());
+        assert!(data.is_ranked());
+    }
+
+    #[test]
+    fn league_data_opt_returns_none_if_not_applicable() {
+        let data = league_data(-1., -1);
+        assert_eq!(data.rating_opt(), None);
+        assert_eq!(data.glicko_opt(), None);
+        assert_eq!(data.gxe_opt(), None);
+        assert_eq!(data.standing_opt(), None);
+        assert_eq!(data.standing_local_opt(), None);
+    }
+
+    #[test]
+    fn league_data_opt_returns_some_if_applicable() {
+        let data = league_data(0., 0);
+        assert_eq!(data.rating_opt(), Some(0.));
+        assert_eq!(data.standing_opt(), Some(0));
+        let data = league_data(15200.5, 42);
+        assert_eq!(data.rating_opt(), Some(15200.5));
+        assert_eq!(data.glicko_opt(), Some(15200.5));
+        assert_eq!(data.gxe_opt(), Some(15200.5));
+        assert_eq!(data.standing_opt(), Some(42));
+        assert_eq!(data.standing_local_opt(), Some(42));
+    }
+}
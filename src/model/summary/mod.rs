@@ -1,6 +1,7 @@
 //! Easy-to-use models of the various objects received from the User Summaries API endpoints.
 
-use crate::model::prelude::*;
+use crate::{client::param::record::Gamemode as RecordGamemode, model::prelude::*};
+use std::collections::HashMap;
 
 pub mod blitz;
 pub mod forty_lines;
@@ -24,11 +25,62 @@ pub struct AllSummaries {
     #[serde(rename = "zenithex")]
     pub zenith_ex: zenith::Zenith,
     /// The user's TETRA LEAGUE summary data.
-    pub league: league::LeagueData,
+    ///
+    /// This is [`LeagueDataWrap::Empty`](league::LeagueDataWrap::Empty) if the user is banned.
+    pub league: league::LeagueDataWrap,
     /// The user's ZEN summary data.
     pub zen: zen::Zen,
     /// The user's achievements.
     pub achievements: Vec<Achievement>,
+    /// The summaries not modeled by this crate yet, keyed by their names.
+    ///
+    /// New summary modes added to the API are preserved here.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl AllSummaries {
+    /// Returns the user's record of the specified game mode.
+    ///
+    /// For QUICK PLAY and EXPERT QUICK PLAY, the record of this week is returned.
+    /// For TETRA LEAGUE, `None` is always returned.
+    pub fn by_gamemode(&self, gamemode: RecordGamemode) -> Option<&Record> {
+        match gamemode {
+            RecordGamemode::FortyLines => self.forty_lines.record.as_ref(),
+            RecordGamemode::Blitz => self.blitz.record.as_ref(),
+            RecordGamemode::Zenith => self.zenith.record.as_ref(),
+            RecordGamemode::ZenithEx => self.zenith_ex.record.as_ref(),
+            RecordGamemode::League => None,
+        }
+    }
+
+    /// Returns an iterator over the user's records with their game modes.
+    ///
+    /// The game modes that the user has no record of are skipped.
+    pub fn records(&self) -> impl Iterator<Item = (RecordGamemode, &Record)> {
+        [
+            RecordGamemode::FortyLines,
+            RecordGamemode::Blitz,
+            RecordGamemode::Zenith,
+            RecordGamemode::ZenithEx,
+        ]
+        .into_iter()
+        .filter_map(|gm| self.by_gamemode(gm.clone()).map(|r| (gm, r)))
+    }
+
+    /// Returns the user's best time of 40 LINES in milliseconds.
+    ///
+    /// If the user has never played 40 LINES, `None` is returned.
+    pub fn best_time_40l(&self) -> Option<f64> {
+        self.forty_lines.best_time_ms()
+    }
+
+    /// Returns the user's best score of BLITZ.
+    ///
+    /// If the user has never played BLITZ, `None` is returned.
+    pub fn best_blitz_score(&self) -> Option<u64> {
+        self.blitz.best_score()
+    }
 }
 
 impl AsRef<AllSummaries> for AllSummaries {
@@ -36,3 +88,185 @@ impl AsRef<AllSummaries> for AllSummaries {
         self
     }
 }
+
+/// A struct that contains some summaries of a user.
+///
+/// Only the summaries requested by the [`Client::get_user_summaries`](crate::client::Client::get_user_summaries)
+/// method are `Some`.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct PartialSummaries {
+    /// The user's 40 LINES summary data.
+    pub forty_lines: Option<forty_lines::FortyLines>,
+    /// The user's BLITZ summary data.
+    pub blitz: Option<blitz::Blitz>,
+    /// The user's QUICK PLAY summary data.
+    pub zenith: Option<zenith::Zenith>,
+    /// The user's EXPERT QUICK PLAY summary data.
+    pub zenith_ex: Option<zenith::Zenith>,
+    /// The user's TETRA LEAGUE summary data.
+    pub league: Option<league::LeagueDataWrap>,
+    /// The user's ZEN summary data.
+    pub zen: Option<zen::Zen>,
+    /// The user's achievements.
+    pub achievements: Option<Vec<Achievement>>,
+}
+
+impl AsRef<PartialSummaries> for PartialSummaries {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// Returns `-1`, which means "not ranked" in the summaries.
+///
+/// This is used as the default value of the missing ranks,
+/// since the API may return an empty summary for banned users.
+fn not_ranked() -> i32 {
+    -1
+}
+
+/// Returns the value of the specified key in the final stats of the single-player record.
+pub(crate) fn final_stat<'a>(record: &'a Record, key: &str) -> Option<&'a serde_json::Value> {
+    match &record.results {
+        record::Results::SinglePlayer(r) => r.final_stats.get(key),
+        _ => None,
+    }
+}
+
+/// Returns a JSON of a single-player record with the specified final stats.
+///
+/// This is a fixture for the tests of the summaries.
+#[cfg(test)]
+pub(crate) fn record_json(gamemode: &str, stats: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "_id": "6575b4a2dc1b3a8ef2e6a2a0",
+        "replayid": "VAF76VXt1Jr2Xhtx",
+        "stub": false,
+        "gamemode": gamemode,
+        "pb": true,
+        "oncepb": true,
+        "ts": "2023-12-10T12:58:10.123Z",
+        "otherusers": [],
+        "leaderboards": [],
+        "disputed": false,
+        "results": {
+            "stats": stats,
+            "aggregatestats": {},
+            "gameoverreason": "clear"
+        },
+        "extras": {}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn all_summaries() -> AllSummaries {
+        serde_json::from_value(all_summaries_json()).unwrap()
+    }
+
+    fn all_summaries_json() -> Value {
+        json!({
+            "40l": {
+                "record": record_json("40l", json!({ "finaltime": 52321.5 })),
+                "rank": 1234,
+                "rank_local": 56
+            },
+            "blitz": {
+                "record": record_json("blitz", json!({ "score": 123456 })),
+                "rank": -1,
+                "rank_local": -1
+            },
+            "zenith": {
+                "record": record_json("zenith", json!({ "zenith": { "altitude": 812.3 } })),
+                "rank": 42,
+                "rank_local": 7,
+                "best": { "record": null, "rank": -1 }
+            },
+            "zenithex": {
+                "record": null,
+                "rank": -1,
+                "rank_local": -1,
+                "best": { "record": null, "rank": -1 }
+            },
+            "league": {
+                "gamesplayed": 0,
+                "gameswon": 0,
+                "glicko": -1,
+                "rd": null,
+                "decaying": false,
+                "tr": -1,
+                "gxe": -1,
+                "rank": "z",
+                "past": {}
+            },
+            "zen": { "level": 12, "score": 345678.0 },
+            "achievements": []
+        })
+    }
+
+    #[test]
+    fn all_summaries_by_gamemode_returns_matching_record() {
+        let summaries = all_summaries();
+        let gamemode = |gm| summaries.by_gamemode(gm).map(|r| r.game_mode.to_string());
+        assert_eq!(gamemode(RecordGamemode::FortyLines).as_deref(), Some("40l"));
+        assert_eq!(gamemode(RecordGamemode::Blitz).as_deref(), Some("blitz"));
+        assert_eq!(gamemode(RecordGamemode::Zenith).as_deref(), Some("zenith"));
+        assert_eq!(gamemode(RecordGamemode::ZenithEx), None);
+        assert_eq!(gamemode(RecordGamemode::League), None);
+    }
+
+    #[test]
+    fn all_summaries_records_skips_missing_records() {
+        let summaries = all_summaries();
+        let gamemodes: Vec<String> = summaries
+            .records()
+            .map(|(_, r)| r.game_mode.to_string())
+            .collect();
+        assert_eq!(gamemodes, ["40l", "blitz", "zenith"]);
+    }
+
+    #[test]
+    fn all_summaries_best_returns_final_stats() {
+        let summaries = all_summaries();
+        assert_eq!(summaries.best_time_40l(), Some(52321.5));
+        assert_eq!(summaries.best_blitz_score(), Some(123456));
+        assert_eq!(summaries.zen.level, 12);
+        assert_eq!(summaries.league.unwrap().rating_opt(), None);
+    }
+
+    #[test]
+    fn all_summaries_deserializes_banned_user() {
+        let summaries: AllSummaries = serde_json::from_value(json!({
+            "40l": {},
+            "blitz": {},
+            "zenith": {},
+            "zenithex": {},
+            "league": {},
+            "zen": {},
+            "achievements": []
+        }))
+        .unwrap();
+        assert!(summaries.records().next().is_none());
+        assert_eq!(summaries.forty_lines.rank, -1);
+        assert_eq!(summaries.blitz.rank_local, -1);
+        assert_eq!(summaries.zenith_ex.best.rank, -1);
+        assert!(summaries.league.is_empty());
+        assert_eq!(summaries.zen.level, 0);
+        assert!(summaries.extra.is_empty());
+    }
+
+    #[test]
+    fn all_summaries_preserves_unknown_modes() {
+        assert!(all_summaries().extra.is_empty());
+        let mut json = all_summaries_json();
+        json["newmode"] = json!({ "record": null, "rank": -1 });
+        let summaries: AllSummaries = serde_json::from_value(json).unwrap();
+        assert_eq!(summaries.extra.len(), 1);
+        assert_eq!(summaries.extra["newmode"]["rank"], -1);
+        assert_eq!(summaries.zen.level, 12);
+    }
+}
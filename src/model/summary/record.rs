@@ -2,7 +2,7 @@
 //!
 //! For more details, see the [API document](https://tetr.io/about/api/#recorddata).
 
-use crate::model::prelude::*;
+use crate::model::{prelude::*, response::Response};
 use std::collections::HashMap;
 
 /// A record data.
@@ -77,6 +77,29 @@ impl AsRef<Record> for Record {
     }
 }
 
+impl Response<Record> {
+    /// Whether the searched record was found.
+    ///
+    /// This is for the response of [`Client::search_record`](crate::client::Client::search_record).
+    pub fn found(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Returns a reference to the searched record.
+    ///
+    /// If the record was not found, `None` is returned.
+    pub fn record(&self) -> Option<&Record> {
+        self.data.as_ref()
+    }
+
+    /// Returns the searched record, consuming the `self` value.
+    ///
+    /// If the record was not found, `None` is returned.
+    pub fn into_record(self) -> Option<Record> {
+        self.data
+    }
+}
+
 /// Partial information about a user.
 /// This is used in the [`Record`] struct.
 #[derive(Clone, Debug, Deserialize)]
@@ -146,6 +169,17 @@ impl Results {
     pub fn is_unknown_structure(&self) -> bool {
         matches!(self, Results::Unknown(_))
     }
+
+    /// Returns the raw JSON value of the results, if the structure is unknown.
+    ///
+    /// This allows to inspect the results that this wrapper does not model yet.
+    /// For the known structures, `None` is returned.
+    pub fn raw(&self) -> Option<&serde_json::Value> {
+        match self {
+            Results::Unknown(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 impl AsRef<Results> for Results {
@@ -185,6 +219,34 @@ pub struct MultiPlayerResults {
     pub rounds: Vec<Vec<PlayerStatsRound>>,
 }
 
+impl MultiPlayerResults {
+    /// Returns the player who won the most rounds.
+    ///
+    /// If the leaderboard is empty or the most wins are tied, `None` is returned.
+    pub fn winner(&self) -> Option<&PlayerStats> {
+        let max_wins = self.leaderboard.iter().map(|p| p.wins).max()?;
+        let mut winners = self.leaderboard.iter().filter(|p| p.wins == max_wins);
+        match (winners.next(), winners.next()) {
+            (Some(winner), None) => Some(winner),
+            _ => None,
+        }
+    }
+
+    /// Whether the specified user won the match.
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id` - The user ID to check.
+    pub fn is_winner(&self, user_id: &UserId) -> bool {
+        self.winner().is_some_and(|w| &w.id == user_id)
+    }
+
+    /// Returns the amount of rounds played.
+    pub fn rounds_count(&self) -> usize {
+        self.rounds.len()
+    }
+}
+
 impl AsRef<MultiPlayerResults> for MultiPlayerResults {
     fn as_ref(&self) -> &Self {
         self
@@ -212,6 +274,13 @@ pub struct PlayerStats {
 impl PlayerStats {
     impl_get_user!(id);
     impl_for_username!();
+
+    /// Returns the aggregate stats across all rounds as a [`RoundStats`].
+    ///
+    /// If the stats do not have the expected structure, `None` is returned.
+    pub fn stats_typed(&self) -> Option<RoundStats> {
+        serde_json::from_value(self.stats.clone()).ok()
+    }
 }
 
 impl AsRef<PlayerStats> for PlayerStats {
@@ -244,6 +313,13 @@ pub struct PlayerStatsRound {
 impl PlayerStatsRound {
     impl_get_user!(id);
     impl_for_username!();
+
+    /// Returns the stats for the player for this round as a [`RoundStats`].
+    ///
+    /// If the stats do not have the expected structure, `None` is returned.
+    pub fn stats_typed(&self) -> Option<RoundStats> {
+        serde_json::from_value(self.stats.clone()).ok()
+    }
 }
 
 impl AsRef<PlayerStatsRound> for PlayerStatsRound {
@@ -252,6 +328,35 @@ impl AsRef<PlayerStatsRound> for PlayerStatsRound {
     }
 }
 
+/// Common stats of a player in a multi-player game.
+///
+/// This is a typed view of [`PlayerStats::stats`] and [`PlayerStatsRound::stats`].
+#[derive(Clone, Debug, Deserialize)]
+#[non_exhaustive]
+pub struct RoundStats {
+    /// The attack per minute.
+    pub apm: f64,
+    /// The pieces per second.
+    pub pps: f64,
+    /// The versus score.
+    #[serde(rename = "vsscore")]
+    pub vs: f64,
+    /// The amount of garbage lines sent.
+    #[serde(rename = "garbagesent")]
+    pub garbage_sent: Option<u32>,
+    /// The amount of garbage lines received.
+    #[serde(rename = "garbagereceived")]
+    pub garbage_received: Option<u32>,
+    /// The amount of players killed.
+    pub kills: Option<u32>,
+}
+
+impl AsRef<RoundStats> for RoundStats {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
 /// Extra metadata for a Record.
 #[derive(Clone, Debug, Deserialize)]
 #[non_exhaustive]
@@ -264,6 +369,40 @@ pub struct Extras {
     pub zenith: Option<Zenith>,
 }
 
+impl Extras {
+    /// Returns the TR change of the specified user in this match.
+    ///
+    /// If the user is not being ranked, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id` - The user ID to look up.
+    pub fn tr_change(&self, user_id: &UserId) -> Option<f64> {
+        self.league_change(user_id)
+            .map(|(before, after)| after.tr - before.tr)
+    }
+
+    /// Returns the ranks of the specified user before and after this match.
+    ///
+    /// If the user is not being ranked, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id` - The user ID to look up.
+    pub fn rank_change(&self, user_id: &UserId) -> Option<(Rank, Rank)> {
+        self.league_change(user_id)
+            .map(|(before, after)| (before.rank.clone(), after.rank.clone()))
+    }
+
+    /// Returns the stats of the specified user before and after this match.
+    fn league_change(&self, user_id: &UserId) -> Option<(&PlayerExtraStats, &PlayerExtraStats)> {
+        match self.league.as_ref()?.get(user_id)?.as_slice() {
+            [before, after] => Some((before, after)),
+            _ => None,
+        }
+    }
+}
+
 impl AsRef<Extras> for Extras {
     fn as_ref(&self) -> &Self {
         self
@@ -305,3 +444,253 @@ impl AsRef<Zenith> for Zenith {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::summary::record_json;
+    use serde_json::json;
+
+    fn single_player_record() -> Record {
+        let mut record = record_json("40l", json!({ "finaltime": 52321.5 }));
+        record["revolution"] = serde_json::Value::Null;
+        record["user"] = json!({
+            "id": "621db46d1d638ea850be2aa0",
+            "username": "rinrin-rs",
+            "avatar_revision": null,
+            "banner_revision": null,
+            "country": "JP",
+            "supporter": false
+        });
+        record["leaderboards"] = json!(["40l_global", "40l_country_JP"]);
+        record["results"]["aggregatestats"] = json!({ "apm": 0.0, "pps": 2.1, "vsscore": 0.0 });
+        serde_json::from_value(record).unwrap()
+    }
+
+    fn league_record() -> Record {
+        let player = |id: &str, username: &str, wins: u32| {
+            json!({
+                "id": id,
+                "username": username,
+                "active": true,
+                "naturalorder": 0,
+                "shadows": [],
+                "shadowedBy": [null, null],
+                "wins": wins,
+                "stats": { "apm": 120.5, "pps": 2.3, "vsscore": 250.1 }
+            })
+        };
+        let round_player = |id: &str, username: &str, alive: bool| {
+            json!({
+                "id": id,
+                "username": username,
+                "active": true,
+                "naturalorder": 0,
+                "shadows": [],
+                "shadowedBy": [null, null],
+                "alive": alive,
+                "lifetime": 95823,
+                "stats": { "apm": 120.5, "pps": 2.3, "vsscore": 250.1 }
+            })
+        };
+        let extra = |tr: f64| json!({ "glicko": 2000.0, "rd": 60.0, "tr": tr, "rank": "s", "placement": null });
+        serde_json::from_value(json!({
+            "_id": "66b0a8f2d9c4b6d2f4c6e8a0",
+            "replayid": "66b0a8f2d9c4b6d2f4c6e8a0",
+            "stub": false,
+            "gamemode": "league",
+            "pb": false,
+            "oncepb": false,
+            "ts": "2024-08-05T10:00:00.000Z",
+            "revolution": null,
+            "user": {
+                "id": "621db46d1d638ea850be2aa0",
+                "username": "rinrin-rs",
+                "avatar_revision": null,
+                "banner_revision": null,
+                "country": "JP",
+                "supporter": false
+            },
+            "otherusers": [{
+                "id": "5e32fc85ab319c2ab1beb07c",
+                "username": "osk",
+                "avatar_revision": 1700000000000u64,
+                "banner_revision": null,
+                "country": null,
+                "supporter": true
+            }],
+            "leaderboards": [],
+            "disputed": false,
+            "results": {
+                "leaderboard": [
+                    player("621db46d1d638ea850be2aa0", "rinrin-rs", 7),
+                    player("5e32fc85ab319c2ab1beb07c", "osk", 3)
+                ],
+                "rounds": [[
+                    round_player("621db46d1d638ea850be2aa0", "rinrin-rs", true),
+                    round_player("5e32fc85ab319c2ab1beb07c", "osk", false)
+                ]]
+            },
+            "extras": {
+                "league": {
+                    "621db46d1d638ea850be2aa0": [extra(15000.0), extra(15100.0)],
+                    "5e32fc85ab319c2ab1beb07c": [extra(16000.0), extra(15900.0)]
+                },
+                "result": "victory"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn league_record_deserializes_into_multi_player_results() {
+        let record = league_record();
+        assert!(record.game_mode.is_league());
+        assert!(matches!(
+            record.game_mode.to_record_gamemode(),
+            Ok(crate::client::param::record::Gamemode::League)
+        ));
+        assert!(record.results.is_multi_play());
+        let Results::MultiPlayer(results) = &record.results else {
+            unreachable!()
+        };
+        assert_eq!(results.leaderboard[0].username, "rinrin-rs");
+        assert_eq!(results.leaderboard[0].wins, 7);
+        assert_eq!(results.rounds[0].len(), 2);
+        assert!(!results.rounds[0][1].is_alive);
+        let league = record.extras.league.as_ref().unwrap();
+        assert_eq!(league.len(), 2);
+        assert_eq!(record.extras.result.as_deref(), Some("victory"));
+    }
+
+    #[test]
+    fn multi_player_results_winner_returns_player_with_most_wins() {
+        let Results::MultiPlayer(mut results) = league_record().results else {
+            unreachable!()
+        };
+        let rinrin: UserId = "621db46d1d638ea850be2aa0".parse().unwrap();
+        let osk: UserId = "5e32fc85ab319c2ab1beb07c".parse().unwrap();
+        assert_eq!(results.winner().unwrap().username, "rinrin-rs");
+        assert!(results.is_winner(&rinrin));
+        assert!(!results.is_winner(&osk));
+        assert_eq!(results.rounds_count(), 1);
+        results.leaderboard[1].wins = 7;
+        assert!(results.winner().is_none());
+        assert!(!results.is_winner(&rinrin));
+        results.leaderboard.clear();
+        assert!(results.winner().is_none());
+    }
+
+    #[test]
+    fn player_stats_typed_returns_common_stats() {
+        let Results::MultiPlayer(results) = league_record().results else {
+            unreachable!()
+        };
+        let stats = results.leaderboard[0].stats_typed().unwrap();
+        assert_eq!((stats.apm, stats.pps, stats.vs), (120.5, 2.3, 250.1));
+        assert!(stats.kills.is_none());
+        let round: PlayerStatsRound = serde_json::from_value(json!({
+            "id": "621db46d1d638ea850be2aa0",
+            "username": "rinrin-rs",
+            "active": true,
+            "alive": true,
+            "lifetime": 95823,
+            "stats": {
+                "apm": 150.2,
+                "pps": 2.8,
+                "vsscore": 310.7,
+                "garbagesent": 58,
+                "garbagereceived": 41,
+                "kills": 1,
+                "altitude": 0,
+                "rank": 1
+            }
+        }))
+        .unwrap();
+        let stats = round.stats_typed().unwrap();
+        assert_eq!(stats.vs, 310.7);
+        assert_eq!(stats.garbage_sent, Some(58));
+        assert_eq!(stats.garbage_received, Some(41));
+        assert_eq!(stats.kills, Some(1));
+        assert_eq!(round.stats["altitude"], 0);
+    }
+
+    #[test]
+    fn player_stats_typed_returns_none_if_unknown_structure() {
+        let Results::MultiPlayer(mut results) = league_record().results else {
+            unreachable!()
+        };
+        results.rounds[0][0].stats = json!({ "apm": "fast" });
+        assert!(results.rounds[0][0].stats_typed().is_none());
+    }
+
+    #[test]
+    fn extras_tr_change_returns_after_minus_before() {
+        let record = league_record();
+        let rinrin: UserId = "621db46d1d638ea850be2aa0".parse().unwrap();
+        let osk: UserId = "5e32fc85ab319c2ab1beb07c".parse().unwrap();
+        assert_eq!(record.extras.tr_change(&rinrin), Some(100.));
+        assert_eq!(record.extras.tr_change(&osk), Some(-100.));
+        assert_eq!(record.extras.rank_change(&rinrin), Some((Rank::S, Rank::S)));
+    }
+
+    #[test]
+    fn extras_tr_change_returns_none_if_not_ranked() {
+        let unknown: UserId = "000000000000000000000000".parse().unwrap();
+        assert!(league_record().extras.tr_change(&unknown).is_none());
+        assert!(league_record().extras.rank_change(&unknown).is_none());
+        let extras = single_player_record().extras;
+        assert!(extras.tr_change(&unknown).is_none());
+    }
+
+    #[test]
+    fn single_player_record_deserializes_into_single_player_results() {
+        let record = single_player_record();
+        assert!(record.results.is_single_play());
+        assert!(!record.results.is_multi_play());
+    }
+
+    #[test]
+    fn searched_record_response_returns_record_if_found() {
+        let rsp = Response {
+            is_success: true,
+            error: None,
+            cache: None,
+            data: Some(single_player_record()),
+            etag: None,
+        };
+        assert!(rsp.found());
+        assert_eq!(
+            rsp.record().unwrap().replay_id.to_string(),
+            "VAF76VXt1Jr2Xhtx"
+        );
+        assert_eq!(rsp.into_record().unwrap().id, "6575b4a2dc1b3a8ef2e6a2a0");
+    }
+
+    #[test]
+    fn searched_record_response_returns_none_if_not_found() {
+        let rsp: Response<Record> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such record!" }
+        }))
+        .unwrap();
+        assert!(!rsp.found());
+        assert!(rsp.record().is_none());
+        assert!(rsp.into_record().is_none());
+    }
+
+    #[test]
+    fn results_raw_returns_unknown_structure() {
+        let results: Results = serde_json::from_value(json!({ "newstats": { "foo": 1 } })).unwrap();
+        assert!(results.is_unknown_structure());
+        assert_eq!(results.raw().unwrap()["newstats"]["foo"], 1);
+        assert!(single_player_record().results.raw().is_none());
+    }
+
+    #[test]
+    fn record_replay_url_and_submitted_at() {
+        let record = single_player_record();
+        assert_eq!(record.replay_url(), "https://tetr.io/#R:VAF76VXt1Jr2Xhtx");
+        assert_eq!(record.submitted_at(), 1702213090);
+    }
+}
@@ -3,15 +3,17 @@
 //! About the endpoint "User Summary: ZEN",
 //! see the [API document](https://tetr.io/about/api/#usersusersummarieszen).
 
-use crate::model::prelude::*;
+use crate::model::{prelude::*, response::Response};
 
 /// A struct that describes a summary of a user's ZEN progress.
 #[derive(Clone, Debug, Deserialize)]
 #[non_exhaustive]
 pub struct Zen {
     /// The user's ZEN level.
+    #[serde(default)] // If the field is missing, it is 0.
     pub level: u32,
     /// The user's ZEN score.
+    #[serde(default)] // If the field is missing, it is 0.
     pub score: f64,
 }
 
@@ -20,3 +22,47 @@ impl AsRef<Zen> for Zen {
         self
     }
 }
+
+impl Response<Zen> {
+    /// Returns the user's ZEN level.
+    ///
+    /// If the request was unsuccessful, `None` is returned.
+    pub fn level(&self) -> Option<u32> {
+        self.data.as_ref().map(|z| z.level)
+    }
+
+    /// Returns the user's ZEN score.
+    ///
+    /// If the request was unsuccessful, `None` is returned.
+    pub fn score(&self) -> Option<f64> {
+        self.data.as_ref().map(|z| z.score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn zen_response_returns_level_and_score() {
+        let rsp: Response<Zen> = serde_json::from_value(json!({
+            "success": true,
+            "data": { "level": 12, "score": 345678.0 }
+        }))
+        .unwrap();
+        assert_eq!(rsp.level(), Some(12));
+        assert_eq!(rsp.score(), Some(345678.));
+    }
+
+    #[test]
+    fn zen_response_returns_none_if_unsuccessful() {
+        let rsp: Response<Zen> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        assert_eq!(rsp.level(), None);
+        assert_eq!(rsp.score(), None);
+    }
+}
@@ -14,8 +14,10 @@ pub struct Zenith {
     /// The user's QUICK PLAY record, or `None` if the user hasn't played this week.
     pub record: Option<Record>,
     /// The user's rank in global leaderboards, or -1 if not in global leaderboards.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank: i32,
     /// The user's rank in their country's leaderboards, or -1 if not in any.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank_local: i32,
     /// The user's career best.
     ///
@@ -24,6 +26,7 @@ pub struct Zenith {
     /// This is because if the record is at Floor 10,
     /// the final leaderboard position is considered first
     /// (the mode is multiplayer, after all).
+    #[serde(default)] // If the field is missing, there is no career best.
     pub best: ZenithBest,
 }
 
@@ -47,9 +50,20 @@ pub struct ZenithBest {
     pub record: Option<Record>,
     /// The rank said record had in global leaderboards at the end of the week,
     /// or -1 if it was not ranked.
+    #[serde(default = "super::not_ranked")] // If the field is missing, it is -1.
     pub rank: i32,
 }
 
+impl Default for ZenithBest {
+    /// Returns a [`ZenithBest`] without a record.
+    fn default() -> Self {
+        Self {
+            record: None,
+            rank: -1,
+        }
+    }
+}
+
 impl AsRef<ZenithBest> for ZenithBest {
     fn as_ref(&self) -> &Self {
         self
@@ -3,7 +3,11 @@
 //! About the endpoint "User Info",
 //! see the [API document](https://tetr.io/about/api/#usersuser).
 
-use crate::{model::prelude::*, util::deserialize_from_non_str_to_none};
+use crate::{
+    model::{prelude::*, response::Response},
+    util::{deserialize_from_non_str_to_none, deserialize_string_or_f64},
+};
+use std::collections::HashMap;
 
 /// A struct that describes a user in detail.
 #[derive(Clone, Debug, Deserialize)]
@@ -26,6 +30,7 @@ pub struct User {
     /// The user's badges
     pub badges: Vec<Badge>,
     /// The user's XP in points.
+    #[serde(deserialize_with = "deserialize_string_or_f64")]
     pub xp: f64,
     /// The amount of online games played by this user.
     /// If the user has chosen to hide this statistic, it will be -1.
@@ -100,6 +105,40 @@ impl User {
         self.badges.len()
     }
 
+    /// Returns the amount of online games played by the user.
+    ///
+    /// If the user has chosen to hide this statistic, `None` is returned.
+    pub fn play_count_opt(&self) -> Option<i32> {
+        (self.play_count != -1).then_some(self.play_count)
+    }
+
+    /// Returns the amount of online games won by the user.
+    ///
+    /// If the user has chosen to hide this statistic, `None` is returned.
+    pub fn won_count_opt(&self) -> Option<i32> {
+        (self.won_count != -1).then_some(self.won_count)
+    }
+
+    /// Returns the amount of seconds the user spent playing, both on- and offline.
+    ///
+    /// If the user has chosen to hide this statistic, `None` is returned.
+    pub fn play_time_opt(&self) -> Option<f64> {
+        (self.play_time != -1.).then_some(self.play_time)
+    }
+
+    /// Whether the user has connected to the specified platform.
+    pub fn has_connection(&self, platform: Platform) -> bool {
+        self.connection(platform).is_some()
+    }
+
+    /// Returns the user's connection to the specified platform.
+    ///
+    /// If the user has not connected to the platform, `None` is returned.
+    /// This is the same as [`Connections::get`].
+    pub fn connection(&self, platform: Platform) -> Option<&Connection> {
+        self.connections.get(platform)
+    }
+
     impl_for_avatar_revision!();
     impl_for_banner_revision!();
     impl_for_country!();
@@ -111,6 +150,23 @@ impl AsRef<User> for User {
     }
 }
 
+impl Response<User> {
+    /// Returns the user's internal ID.
+    ///
+    /// This can be used for the subsequent requests that take a user ID.
+    /// If the request was unsuccessful, `None` is returned.
+    pub fn canonical_id(&self) -> Option<&UserId> {
+        self.data.as_ref().map(|u| &u.id)
+    }
+
+    /// Returns the user's username, as it is registered.
+    ///
+    /// If the request was unsuccessful, `None` is returned.
+    pub fn canonical_username(&self) -> Option<&str> {
+        self.data.as_ref().map(|u| u.username.as_str())
+    }
+}
+
 /// A user's badge.
 #[derive(Clone, Debug, Deserialize)]
 #[non_exhaustive]
@@ -154,6 +210,56 @@ impl AsRef<Badge> for Badge {
     }
 }
 
+/// An extension trait for slices of [`Badge`]s.
+pub trait BadgeSliceExt {
+    /// Groups the badges by their [`Badge::group`], as TETR.IO renders them.
+    ///
+    /// Each group is placed at the position of its first badge,
+    /// and the order of the badges is preserved.
+    /// Badges without a group are placed as single-element groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetr_ch::model::user::{Badge, BadgeSliceExt};
+    ///
+    /// let badges: Vec<Badge> = serde_json::from_str(
+    ///     r##"[
+    ///         { "id": "leaderboard1", "group": "lb", "label": "#1" },
+    ///         { "id": "kod_founder", "group": null, "label": "KoD founder" },
+    ///         { "id": "leaderboard10", "group": "lb", "label": "#10" }
+    ///     ]"##,
+    /// )
+    /// .unwrap();
+    /// let rows = badges.grouped();
+    /// // The badges of the same group are rendered together.
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[0].len(), 2);
+    /// assert_eq!(rows[1][0].label, "KoD founder");
+    /// ```
+    fn grouped(&self) -> Vec<Vec<&Badge>>;
+}
+
+impl BadgeSliceExt for [Badge] {
+    fn grouped(&self) -> Vec<Vec<&Badge>> {
+        let mut groups: Vec<Vec<&Badge>> = Vec::new();
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        for badge in self {
+            match badge.group.as_deref() {
+                Some(group) => match indices.get(group) {
+                    Some(&i) => groups[i].push(badge),
+                    None => {
+                        indices.insert(group, groups.len());
+                        groups.push(vec![badge]);
+                    }
+                },
+                None => groups.push(vec![badge]),
+            }
+        }
+        groups
+    }
+}
+
 /// A user's third party connections.
 #[derive(Clone, Debug, Deserialize)]
 #[non_exhaustive]
@@ -197,12 +303,62 @@ pub struct Connections {
     pub steam: Option<Connection>,
 }
 
+impl Connections {
+    /// Returns an iterator over the present connections with their platforms.
+    ///
+    /// The platforms the user has not connected to are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (Platform, &Connection)> {
+        [
+            Platform::Discord,
+            Platform::Twitch,
+            Platform::Twitter,
+            Platform::Reddit,
+            Platform::YouTube,
+            Platform::Steam,
+        ]
+        .into_iter()
+        .filter_map(|p| self.get(p).map(|c| (p, c)))
+    }
+
+    /// Returns the connection to the specified platform.
+    ///
+    /// If the user has not connected to the platform, `None` is returned.
+    pub fn get(&self, platform: Platform) -> Option<&Connection> {
+        match platform {
+            Platform::Discord => self.discord.as_ref(),
+            Platform::Twitch => self.twitch.as_ref(),
+            Platform::Twitter => self.twitter.as_ref(),
+            Platform::Reddit => self.reddit.as_ref(),
+            Platform::YouTube => self.youtube.as_ref(),
+            Platform::Steam => self.steam.as_ref(),
+        }
+    }
+}
+
 impl AsRef<Connections> for Connections {
     fn as_ref(&self) -> &Self {
         self
     }
 }
 
+/// A third party platform that a user can connect to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// Discord
+    Discord,
+    /// Twitch
+    Twitch,
+    /// X (kept in the API as twitter)
+    Twitter,
+    /// Reddit
+    Reddit,
+    /// YouTube
+    YouTube,
+    /// Steam
+    Steam,
+}
+
 /// A user's connection.
 #[derive(Clone, Debug, Deserialize)]
 #[non_exhaustive]
@@ -290,8 +446,267 @@ pub struct AchievementRatingCounts {
     pub top3: Option<u32>,
 }
 
+impl AchievementRatingCounts {
+    /// Returns the total amount of ranked achievements this user has,
+    /// from Bronze to Diamond and Issued.
+    ///
+    /// The missing counts are treated as 0.
+    pub fn total(&self) -> u32 {
+        [
+            self.bronze,
+            self.silver,
+            self.gold,
+            self.platinum,
+            self.diamond,
+            self.issued,
+        ]
+        .iter()
+        .flatten()
+        .sum()
+    }
+
+    /// Returns the total amount of leaderboard placements of the competitive achievements,
+    /// from the top 100 to the top 3.
+    ///
+    /// The missing counts are treated as 0.
+    pub fn competitive_total(&self) -> u32 {
+        [
+            self.top100,
+            self.top50,
+            self.top25,
+            self.top10,
+            self.top5,
+            self.top3,
+        ]
+        .iter()
+        .flatten()
+        .sum()
+    }
+}
+
 impl AsRef<AchievementRatingCounts> for AchievementRatingCounts {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user_with_stats(play_count: i32, won_count: i32, play_time: f64) -> User {
+        serde_json::from_value(json!({
+            "_id": "621db46d1d638ea850be2aa0",
+            "username": "rinrin-rs",
+            "role": "user",
+            "badges": [],
+            "xp": 0.0,
+            "gamesplayed": play_count,
+            "gameswon": won_count,
+            "gametime": play_time,
+            "supporter_tier": 0,
+            "connections": {},
+            "achievements": [],
+            "ar": 0,
+            "ar_counts": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn user_response_returns_canonical_id_and_username() {
+        let rsp: Response<User> = serde_json::from_value(json!({
+            "success": true,
+            "data": {
+                "_id": "621db46d1d638ea850be2aa0",
+                "username": "rinrin-rs",
+                "role": "user",
+                "badges": [],
+                "xp": 0.0,
+                "gamesplayed": 0,
+                "gameswon": 0,
+                "gametime": 0.0,
+                "supporter_tier": 0,
+                "connections": {},
+                "achievements": [],
+                "ar": 0,
+                "ar_counts": {}
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            rsp.canonical_id().map(|id| id.to_string()).as_deref(),
+            Some("621db46d1d638ea850be2aa0")
+        );
+        assert_eq!(rsp.canonical_username(), Some("rinrin-rs"));
+    }
+
+    #[test]
+    fn user_response_returns_none_if_unsuccessful() {
+        let rsp: Response<User> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        assert!(rsp.canonical_id().is_none());
+        assert!(rsp.canonical_username().is_none());
+    }
+
+    #[test]
+    fn user_xp_deserializes_from_string() {
+        let mut json = json!({
+            "_id": "621db46d1d638ea850be2aa0",
+            "username": "rinrin-rs",
+            "role": "user",
+            "badges": [],
+            "xp": "4096.5",
+            "gamesplayed": 0,
+            "gameswon": 0,
+            "gametime": 0.0,
+            "supporter_tier": 0,
+            "connections": {},
+            "achievements": [],
+            "ar": 0,
+            "ar_counts": {}
+        });
+        let user: User = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(user.xp, 4096.5);
+        json["xp"] = json!(4096.5);
+        let user: User = serde_json::from_value(json).unwrap();
+        assert_eq!(user.xp, 4096.5);
+    }
+
+    #[test]
+    fn user_stats_opt_returns_none_if_hidden() {
+        let user = user_with_stats(-1, -1, -1.);
+        assert_eq!(user.play_count_opt(), None);
+        assert_eq!(user.won_count_opt(), None);
+        assert_eq!(user.play_time_opt(), None);
+    }
+
+    #[test]
+    fn user_stats_opt_returns_some_if_visible() {
+        let user = user_with_stats(0, 0, 0.);
+        assert_eq!(user.play_count_opt(), Some(0));
+        assert_eq!(user.won_count_opt(), Some(0));
+        assert_eq!(user.play_time_opt(), Some(0.));
+        let user = user_with_stats(120, 64, 3600.5);
+        assert_eq!(user.play_count_opt(), Some(120));
+        assert_eq!(user.won_count_opt(), Some(64));
+        assert_eq!(user.play_time_opt(), Some(3600.5));
+    }
+
+    #[test]
+    fn achievement_rating_counts_totals_treat_missing_as_zero() {
+        let counts = AchievementRatingCounts {
+            bronze: Some(3),
+            silver: None,
+            gold: Some(5),
+            platinum: Some(1),
+            diamond: None,
+            issued: Some(2),
+            top100: Some(4),
+            top50: None,
+            top25: Some(2),
+            top10: None,
+            top5: None,
+            top3: Some(1),
+        };
+        assert_eq!(counts.total(), 11);
+        assert_eq!(counts.competitive_total(), 7);
+        let empty: AchievementRatingCounts = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(empty.total(), 0);
+        assert_eq!(empty.competitive_total(), 0);
+    }
+
+    #[test]
+    fn user_country_name_maps_country_code() {
+        let mut user = user_with_stats(0, 0, 0.);
+        assert_eq!(user.country_name(), None);
+        user.country = Some("JP".to_string());
+        assert_eq!(user.country_name(), Some("Japan"));
+        user.country = Some("XM".to_string());
+        assert_eq!(user.country_name(), None);
+        assert_eq!(user.country.as_deref(), Some("XM"));
+    }
+
+    #[test]
+    fn user_connection_returns_connection_if_connected() {
+        let mut user = user_with_stats(0, 0, 0.);
+        assert!(!user.has_connection(Platform::Discord));
+        assert!(user.connection(Platform::Discord).is_none());
+        user.connections = serde_json::from_value(json!({
+            "discord": {
+                "id": "724976600873041940",
+                "username": "rinrin0413",
+                "display_username": "rinrin0413"
+            }
+        }))
+        .unwrap();
+        assert!(user.has_connection(Platform::Discord));
+        assert!(!user.has_connection(Platform::Twitch));
+        assert_eq!(
+            user.connection(Platform::Discord).unwrap().id,
+            "724976600873041940"
+        );
+    }
+
+    #[test]
+    fn connections_iter_yields_only_present_connections() {
+        let connections: Connections = serde_json::from_value(json!({
+            "discord": {
+                "id": "724976600873041940",
+                "username": "rinrin0413",
+                "display_username": "rinrin0413"
+            },
+            "steam": {
+                "id": "76561198000000000",
+                "username": "Rinrin",
+                "display_username": "Rinrin"
+            }
+        }))
+        .unwrap();
+        let platforms: Vec<(Platform, &str)> = connections
+            .iter()
+            .map(|(p, c)| (p, c.id.as_str()))
+            .collect();
+        assert_eq!(
+            platforms,
+            [
+                (Platform::Discord, "724976600873041940"),
+                (Platform::Steam, "76561198000000000")
+            ]
+        );
+        assert_eq!(connections.get(Platform::Steam).unwrap().username, "Rinrin");
+        assert!(connections.get(Platform::Twitch).is_none());
+    }
+
+    #[test]
+    fn badges_grouped_keeps_order_and_singletons() {
+        let badges: Vec<Badge> = serde_json::from_value(json!([
+            { "id": "a1", "group": "a", "label": "A1" },
+            { "id": "solo1", "group": null, "label": "Solo 1" },
+            { "id": "b1", "group": "b", "label": "B1" },
+            { "id": "a2", "group": "a", "label": "A2" },
+            { "id": "solo2", "label": "Solo 2" },
+            { "id": "b2", "group": "b", "label": "B2" }
+        ]))
+        .unwrap();
+        let ids: Vec<Vec<String>> = badges
+            .grouped()
+            .iter()
+            .map(|g| g.iter().map(|b| b.id.to_string()).collect())
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                vec!["a1", "a2"],
+                vec!["solo1"],
+                vec!["b1", "b2"],
+                vec!["solo2"]
+            ]
+        );
+        assert!(Vec::<Badge>::new().grouped().is_empty());
+    }
+}
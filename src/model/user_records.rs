@@ -13,8 +13,117 @@ pub struct UserRecords {
     pub entries: Vec<Record>,
 }
 
+impl UserRecords {
+    /// Returns the records as a slice.
+    pub fn records(&self) -> &[Record] {
+        &self.entries
+    }
+
+    /// Returns the records, consuming the `self` value.
+    pub fn into_records(self) -> Vec<Record> {
+        self.entries
+    }
+
+    /// Returns the top entry.
+    ///
+    /// The entries are sorted by the requested leaderboard,
+    /// so this is the best record only if the records were requested from
+    /// [`LeaderboardType::Top`](crate::client::param::record::LeaderboardType::Top).
+    /// If there are no records, `None` is returned.
+    pub fn best(&self) -> Option<&Record> {
+        self.entries.first()
+    }
+
+    /// Returns the records submitted within the specified range.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The UNIX timestamp (inclusive) of the start of the range.
+    /// - `to` - The UNIX timestamp (inclusive) of the end of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if failed to parse the timestamp of a record.
+    pub fn submitted_between(&self, from: i64, to: i64) -> Vec<&Record> {
+        self.entries
+            .iter()
+            .filter(|r| (from..=to).contains(&r.submitted_at()))
+            .collect()
+    }
+}
+
 impl AsRef<UserRecords> for UserRecords {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn record(id: &str, ts: &str) -> serde_json::Value {
+        json!({
+            "_id": id,
+            "replayid": id,
+            "stub": false,
+            "gamemode": "40l",
+            "pb": false,
+            "oncepb": false,
+            "ts": ts,
+            "otherusers": [],
+            "leaderboards": [],
+            "disputed": false,
+            "results": {},
+            "extras": {}
+        })
+    }
+
+    fn user_records() -> UserRecords {
+        serde_json::from_value(json!({
+            "entries": [
+                record("d", "2024-08-04T00:00:00.000Z"),
+                record("c", "2024-08-03T00:00:00.000Z"),
+                record("b", "2024-08-02T00:00:00.000Z"),
+                record("a", "2024-08-01T00:00:00.000Z"),
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn user_records_submitted_between_returns_only_in_range_records() {
+        let records = user_records();
+        // 2024-08-02T00:00:00Z..=2024-08-03T00:00:00Z
+        let ids: Vec<&str> = records
+            .submitted_between(1722556800, 1722643200)
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(ids, ["c", "b"]);
+    }
+
+    #[test]
+    fn user_records_records_returns_all_entries() {
+        let records = user_records();
+        let ids: Vec<&str> = records.records().iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["d", "c", "b", "a"]);
+        let ids: Vec<String> = records.into_records().into_iter().map(|r| r.id).collect();
+        assert_eq!(ids, ["d", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn user_records_best_returns_top_entry() {
+        assert_eq!(user_records().best().unwrap().id, "d");
+        let empty: UserRecords = serde_json::from_value(json!({ "entries": [] })).unwrap();
+        assert!(empty.best().is_none());
+    }
+
+    #[test]
+    fn user_records_submitted_between_returns_empty_if_out_of_range() {
+        let records = user_records();
+        assert!(records.submitted_between(0, 1722470399).is_empty());
+        assert!(records.submitted_between(1722729601, i64::MAX).is_empty());
+    }
+}
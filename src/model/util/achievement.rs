@@ -2,7 +2,8 @@
 //!
 //! For more details, see the [API document](https://tetr.io/about/api/#achievementdata).
 
-use crate::model::prelude::*;
+use crate::model::{achievement_info::Cutoffs, prelude::*, response::Response};
+use std::collections::BTreeMap;
 
 /// An achievement.
 #[derive(Clone, Debug, Deserialize)]
@@ -86,8 +87,251 @@ pub struct Achievement {
     pub rank: Option<u32>,
 }
 
+impl Achievement {
+    /// Whether the user has achieved a rank in the achievement.
+    ///
+    /// If the [`Achievement::rank`] is `None` or `0` (NONE), `false` is returned.
+    pub fn is_achieved(&self) -> bool {
+        self.rank.is_some_and(|r| r != 0)
+    }
+
+    /// Returns the name of the rank of the achievement.
+    ///
+    /// If the [`Achievement::rank`] is `None`, `"None"` is returned.
+    /// If the rank is not documented, `"Unknown"` is returned.
+    pub fn tier_name(&self) -> &'static str {
+        match self.rank.unwrap_or(0) {
+            0 => "None",
+            1 => "Bronze",
+            2 => "Silver",
+            3 => "Gold",
+            4 => "Platinum",
+            5 => "Diamond",
+            100 => "Issued",
+            _ => "Unknown",
+        }
+    }
+
+    /// Returns the progress towards the next rank as a percentage (0 to 100).
+    ///
+    /// The progress is measured from the score required for the current rank
+    /// (or [`Achievement::min`] if no rank is achieved yet)
+    /// to the score required for the next rank, which are taken from the given cutoffs.
+    /// The cutoffs can be got by the [`Client::get_achievement_info`](crate::client::Client::get_achievement_info) method.
+    ///
+    /// `None` is returned if:
+    ///
+    /// - the achievement has no value.
+    /// - the rank is already Diamond or Issued.
+    /// - the cutoffs do not have the score required for the next rank.
+    pub fn progress_percent(&self, cutoffs: &Cutoffs) -> Option<f64> {
+        let value = self.value?;
+        let rank = self.rank.unwrap_or(0);
+        if !(0..5).contains(&rank) {
+            return None;
+        }
+        let next = (rank + 1..=5).find_map(|r| cutoff_of(cutoffs, r))?;
+        let current = cutoff_of(cutoffs, rank).unwrap_or(self.min as f64);
+        if next <= current {
+            return None;
+        }
+        Some(((value - current) / (next - current) * 100.).clamp(0., 100.))
+    }
+}
+
+/// Returns the score required to obtain the specified rank.
+fn cutoff_of(cutoffs: &Cutoffs, rank: u32) -> Option<f64> {
+    match rank {
+        1 => cutoffs.bronze,
+        2 => cutoffs.silver,
+        3 => cutoffs.gold,
+        4 => cutoffs.platinum,
+        5 => cutoffs.diamond,
+        _ => None,
+    }
+}
+
 impl AsRef<Achievement> for Achievement {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+impl Response<Vec<Achievement>> {
+    /// Returns the achievements grouped by their category.
+    ///
+    /// If the request was unsuccessful, an empty map is returned.
+    pub fn by_category(&self) -> BTreeMap<&str, Vec<&Achievement>> {
+        let mut categories: BTreeMap<&str, Vec<&Achievement>> = BTreeMap::new();
+        for a in self.data.iter().flatten() {
+            categories.entry(a.category.as_str()).or_default().push(a);
+        }
+        categories
+    }
+
+    /// Returns the achievements the user has achieved a rank in.
+    ///
+    /// If the request was unsuccessful, an empty vector is returned.
+    pub fn completed(&self) -> Vec<&Achievement> {
+        self.data
+            .iter()
+            .flatten()
+            .filter(|a| a.is_achieved())
+            .collect()
+    }
+
+    /// Returns the achievements the user has not achieved a rank in yet.
+    ///
+    /// If the request was unsuccessful, an empty vector is returned.
+    pub fn in_progress(&self) -> Vec<&Achievement> {
+        self.data
+            .iter()
+            .flatten()
+            .filter(|a| !a.is_achieved())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn achievement(id: u32, category: &str, rank: Option<u32>) -> Value {
+        json!({
+            "k": id,
+            "category": category,
+            "name": format!("achievement {}", id),
+            "object": "objective",
+            "desc": "description",
+            "o": id,
+            "rt": 1,
+            "vt": 1,
+            "art": 1,
+            "min": 1,
+            "deci": 0,
+            "hidden": false,
+            "v": 42.0,
+            "rank": rank
+        })
+    }
+
+    fn achievements() -> Response<Vec<Achievement>> {
+        serde_json::from_value(json!({
+            "success": true,
+            "data": [
+                achievement(1, "general", Some(3)),
+                achievement(2, "sprint", Some(0)),
+                achievement(3, "general", None),
+                achievement(4, "sprint", Some(100))
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn cutoffs() -> Cutoffs {
+        serde_json::from_value(json!({
+            "total": 1000,
+            "diamond": 100.0,
+            "platinum": 80.0,
+            "gold": 60.0,
+            "silver": 40.0,
+            "bronze": 20.0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn achievement_tier_name_maps_ranks() {
+        let tier_name = |rank: Option<u32>| {
+            serde_json::from_value::<Achievement>(achievement(1, "general", rank))
+                .unwrap()
+                .tier_name()
+        };
+        assert_eq!(tier_name(None), "None");
+        assert_eq!(tier_name(Some(0)), "None");
+        assert_eq!(tier_name(Some(1)), "Bronze");
+        assert_eq!(tier_name(Some(5)), "Diamond");
+        assert_eq!(tier_name(Some(100)), "Issued");
+        assert_eq!(tier_name(Some(42)), "Unknown");
+    }
+
+    #[test]
+    fn achievement_progress_percent_is_relative_to_next_cutoff() {
+        let cutoffs = cutoffs();
+        // The value is 42.0, so it is 10% of the way from Silver (40) to Gold (60).
+        let silver: Achievement =
+            serde_json::from_value(achievement(1, "general", Some(2))).unwrap();
+        assert!((silver.progress_percent(&cutoffs).unwrap() - 10.).abs() < 1e-9);
+        // No rank yet, so 10.5 is half the way from the minimum score (1) to Bronze (20).
+        let mut json = achievement(1, "general", Some(0));
+        json["v"] = json!(10.5);
+        let unranked: Achievement = serde_json::from_value(json).unwrap();
+        assert!((unranked.progress_percent(&cutoffs).unwrap() - 50.).abs() < 1e-9);
+        let diamond: Achievement =
+            serde_json::from_value(achievement(1, "general", Some(5))).unwrap();
+        assert_eq!(diamond.progress_percent(&cutoffs), None);
+    }
+
+    #[test]
+    fn achievement_progress_percent_is_clamped() {
+        let cutoffs = cutoffs();
+        // The value 42.0 is already beyond Bronze (20) while no rank is achieved yet.
+        let unranked: Achievement =
+            serde_json::from_value(achievement(1, "general", Some(0))).unwrap();
+        assert_eq!(unranked.progress_percent(&cutoffs), Some(100.));
+        // The value 42.0 is below Gold (60) while the rank is already Gold.
+        let gold: Achievement = serde_json::from_value(achievement(1, "general", Some(3))).unwrap();
+        assert_eq!(gold.progress_percent(&cutoffs), Some(0.));
+    }
+
+    #[test]
+    fn achievement_progress_percent_skips_missing_cutoffs() {
+        // Like the PERCENTILEVLAX rank type, which has no Silver and Bronze.
+        let cutoffs: Cutoffs = serde_json::from_value(json!({
+            "total": 1000,
+            "diamond": 100.0,
+            "platinum": 50.0,
+            "gold": 83.0
+        }))
+        .unwrap();
+        let mut json = achievement(1, "general", None);
+        // It is measured from the minimum score (1) to Gold (83).
+        let unranked: Achievement = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(unranked.progress_percent(&cutoffs), Some(50.));
+        json["v"] = Value::Null;
+        let no_value: Achievement = serde_json::from_value(json).unwrap();
+        assert_eq!(no_value.progress_percent(&cutoffs), None);
+    }
+
+    #[test]
+    fn achievements_by_category_groups_achievements() {
+        let rsp = achievements();
+        let categories = rsp.by_category();
+        let ids = |c: &str| categories[c].iter().map(|a| a.id).collect::<Vec<_>>();
+        assert_eq!(categories.len(), 2);
+        assert_eq!(ids("general"), [1, 3]);
+        assert_eq!(ids("sprint"), [2, 4]);
+    }
+
+    #[test]
+    fn achievements_completed_and_in_progress_are_disjoint() {
+        let rsp = achievements();
+        let completed: Vec<u32> = rsp.completed().iter().map(|a| a.id).collect();
+        let in_progress: Vec<u32> = rsp.in_progress().iter().map(|a| a.id).collect();
+        assert_eq!(completed, [1, 4]);
+        assert_eq!(in_progress, [2, 3]);
+    }
+
+    #[test]
+    fn achievements_helpers_return_empty_if_unsuccessful() {
+        let rsp: Response<Vec<Achievement>> = serde_json::from_value(json!({
+            "success": false,
+            "error": { "msg": "No such user!" }
+        }))
+        .unwrap();
+        assert!(rsp.by_category().is_empty());
+        assert!(rsp.completed().is_empty());
+        assert!(rsp.in_progress().is_empty());
+    }
+}
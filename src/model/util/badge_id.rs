@@ -9,6 +9,9 @@ pub struct BadgeId(String);
 
 impl BadgeId {
     /// Returns the badge icon URL.
+    ///
+    /// Badge IDs may include forward slashes.
+    /// They are kept as they are (not percent-encoded) to follow the folder structure.
     pub fn icon_url(&self) -> String {
         format!("https://tetr.io/res/badges/{}.png", self.0)
     }
@@ -25,3 +28,17 @@ impl fmt::Display for BadgeId {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badge_id_icon_url_preserves_slashes() {
+        let id: BadgeId = serde_json::from_str("\"some/nested/badge\"").unwrap();
+        assert_eq!(
+            id.icon_url(),
+            "https://tetr.io/res/badges/some/nested/badge.png"
+        );
+    }
+}
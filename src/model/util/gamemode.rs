@@ -14,14 +14,39 @@ impl Gamemode {
     /// Converts into a [`crate::client::param::record::Gamemode`].
     /// If failed, returns the game mode as is as `Err<String>`.
     pub fn to_record_gamemode(&self) -> Result<record::Gamemode, String> {
-        match self.0.as_str() {
-            "40l" => Ok(RecordGm::FortyLines),
-            "blitz" => Ok(RecordGm::Blitz),
-            "zenith" => Ok(RecordGm::Zenith),
-            "zenithex" => Ok(RecordGm::ZenithEx),
-            "league" => Ok(RecordGm::League),
-            _ => Err(self.0.clone()),
-        }
+        RecordGm::from_param(&self.0).ok_or_else(|| self.0.clone())
+    }
+
+    /// Returns the human-readable name of the game mode.
+    ///
+    /// If the game mode is not one of the game modes of the records, `None` is returned.
+    pub fn display_name(&self) -> Option<&'static str> {
+        self.to_record_gamemode().ok().map(|gm| gm.display_name())
+    }
+
+    /// Whether the game mode is 40 LINES.
+    pub fn is_sprint(&self) -> bool {
+        self.0 == "40l"
+    }
+
+    /// Whether the game mode is BLITZ.
+    pub fn is_blitz(&self) -> bool {
+        self.0 == "blitz"
+    }
+
+    /// Whether the game mode is QUICK PLAY or EXPERT QUICK PLAY.
+    pub fn is_quick_play(&self) -> bool {
+        matches!(self.0.as_str(), "zenith" | "zenithex")
+    }
+
+    /// Whether the game mode is EXPERT QUICK PLAY.
+    pub fn is_expert_quick_play(&self) -> bool {
+        self.0 == "zenithex"
+    }
+
+    /// Whether the game mode is TETRA LEAGUE.
+    pub fn is_league(&self) -> bool {
+        self.0 == "league"
     }
 }
 
@@ -31,8 +56,112 @@ impl AsRef<Gamemode> for Gamemode {
     }
 }
 
+impl From<RecordGm> for Gamemode {
+    /// Converts from a [`crate::client::param::record::Gamemode`].
+    fn from(gm: RecordGm) -> Self {
+        Self(gm.to_param())
+    }
+}
+
+impl TryFrom<Gamemode> for RecordGm {
+    type Error = String;
+
+    /// Converts into a [`crate::client::param::record::Gamemode`].
+    /// If failed, returns the game mode as is as `Err<String>`.
+    fn try_from(gm: Gamemode) -> Result<Self, Self::Error> {
+        RecordGm::from_param(&gm.0).ok_or(gm.0)
+    }
+}
+
 impl fmt::Display for Gamemode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gamemode(gm: &str) -> Gamemode {
+        Gamemode(gm.to_string())
+    }
+
+    #[test]
+    fn gamemode_display_prints_param_str() {
+        assert_eq!(format!("{}", gamemode("blitz")), "blitz");
+        assert_eq!(format!("{}", gamemode("40l")), "40l");
+    }
+
+    #[test]
+    fn gamemode_display_name_returns_human_readable_name() {
+        assert_eq!(gamemode("40l").display_name(), Some("40 LINES"));
+        assert_eq!(gamemode("blitz").display_name(), Some("BLITZ"));
+        assert_eq!(gamemode("zenith").display_name(), Some("QUICK PLAY"));
+        assert_eq!(
+            gamemode("zenithex").display_name(),
+            Some("EXPERT QUICK PLAY")
+        );
+        assert_eq!(gamemode("league").display_name(), Some("TETRA LEAGUE"));
+        assert_eq!(gamemode("custom").display_name(), None);
+    }
+
+    #[test]
+    fn gamemode_round_trips_through_record_gamemode() {
+        for gm in [
+            RecordGm::FortyLines,
+            RecordGm::Blitz,
+            RecordGm::Zenith,
+            RecordGm::ZenithEx,
+            RecordGm::League,
+        ] {
+            let param = gm.to_param();
+            let model = Gamemode::from(gm);
+            assert_eq!(model.to_string(), param);
+            assert_eq!(RecordGm::try_from(model).unwrap().to_param(), param);
+        }
+    }
+
+    #[test]
+    fn gamemode_try_into_record_gamemode_returns_unknown_game_mode() {
+        let result = RecordGm::try_from(gamemode("custom"));
+        assert!(matches!(result, Err(gm) if gm == "custom"));
+    }
+
+    #[test]
+    fn gamemode_is_sprint_classifies_40l() {
+        assert!(gamemode("40l").is_sprint());
+        assert!(!gamemode("blitz").is_sprint());
+    }
+
+    #[test]
+    fn gamemode_is_blitz_classifies_blitz() {
+        assert!(gamemode("blitz").is_blitz());
+        assert!(!gamemode("40l").is_blitz());
+    }
+
+    #[test]
+    fn gamemode_is_quick_play_classifies_zenith_and_zenithex() {
+        assert!(gamemode("zenith").is_quick_play());
+        assert!(gamemode("zenithex").is_quick_play());
+        assert!(!gamemode("league").is_quick_play());
+        assert!(!gamemode("zenith").is_expert_quick_play());
+        assert!(gamemode("zenithex").is_expert_quick_play());
+    }
+
+    #[test]
+    fn gamemode_is_league_classifies_league() {
+        assert!(gamemode("league").is_league());
+        assert!(!gamemode("zenith").is_league());
+    }
+
+    #[test]
+    fn gamemode_classifies_unknown_as_nothing() {
+        let gm = gamemode("custom");
+        assert!(!gm.is_sprint());
+        assert!(!gm.is_blitz());
+        assert!(!gm.is_quick_play());
+        assert!(!gm.is_expert_quick_play());
+        assert!(!gm.is_league());
+    }
+}
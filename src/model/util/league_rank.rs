@@ -3,7 +3,10 @@
 use crate::model::prelude::*;
 
 /// An enum for the ranks in TETRA LEAGUE.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// The ranks are ordered from the lowest to the highest,
+/// and [`Rank::Z`] (unranked) is lower than any other rank.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
 pub enum Rank {
     /// D rank.
     #[serde(rename = "d")]
@@ -130,6 +133,14 @@ impl Rank {
         matches!(self, Rank::Z)
     }
 
+    /// Returns the position of the rank in the order from the lowest.
+    fn order(&self) -> u8 {
+        match self {
+            Rank::Z => 0,
+            rank => rank.clone() as u8 + 1,
+        }
+    }
+
     /// Returns the URL of the rank icon.
     ///
     /// # Examples
@@ -298,6 +309,18 @@ impl AsRef<Rank> for Rank {
     }
 }
 
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.order().cmp(&other.order())
+    }
+}
+
 impl fmt::Display for Rank {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -378,6 +401,15 @@ mod tests {
         assert!(unranked_rank.is_unranked());
     }
 
+    #[test]
+    fn ranks_are_ordered_from_lowest() {
+        assert!(Rank::B < Rank::BPlus);
+        assert!(Rank::D < Rank::XPlus);
+        assert!(Rank::U < Rank::X);
+        assert!(Rank::Z < Rank::D);
+        assert_eq!(Rank::SS.max(Rank::S), Rank::SS);
+    }
+
     #[test]
     fn get_rank_icon_url() {
         let rank = Rank::SS;
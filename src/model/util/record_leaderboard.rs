@@ -1,6 +1,9 @@
 //! A model for the record leaderboards.
 
-use crate::client::param::record_leaderboard::{RecordsLeaderboardId, Scope};
+use crate::client::param::{
+    record::Gamemode as RecordGamemode,
+    record_leaderboard::{RecordsLeaderboardId, Scope},
+};
 use crate::model::prelude::*;
 
 /// A record leaderboard.
@@ -19,6 +22,38 @@ impl RecordLeaderboard {
         self._to_id(Some(revolution_id))
     }
 
+    /// Whether the leaderboard is the global one. e.g. `40l_global`.
+    pub fn is_global(&self) -> bool {
+        matches!(self.parse(), Some(id) if matches!(id.scope, Scope::Global))
+    }
+
+    /// Whether the leaderboard is a country one. e.g. `blitz_country_US`.
+    pub fn is_country(&self) -> bool {
+        matches!(self.parse(), Some(id) if matches!(id.scope, Scope::Country(_)))
+    }
+
+    /// Returns the country code of the leaderboard. e.g. `US` for `blitz_country_US`.
+    ///
+    /// If the leaderboard is not a country one or is malformed, `None` is returned.
+    pub fn country_code(&self) -> Option<String> {
+        match self.parse()?.scope {
+            Scope::Country(country) => Some(country),
+            Scope::Global => None,
+        }
+    }
+
+    /// Returns the game mode of the leaderboard.
+    ///
+    /// If the game mode is unknown or the leaderboard is malformed, `None` is returned.
+    pub fn gamemode(&self) -> Option<RecordGamemode> {
+        RecordGamemode::from_param(&self.parse()?.gamemode)
+    }
+
+    /// Parses into a [`RecordsLeaderboardId`].
+    fn parse(&self) -> Option<RecordsLeaderboardId> {
+        RecordsLeaderboardId::from_param(&self.0)
+    }
+
     /// Converts into a [`crate::client::param::record_leaderboard::RecordsLeaderboardId`] with an optional Revolution ID.
     fn _to_id(&self, revolution_id: Option<&str>) -> RecordsLeaderboardId {
         let split_id: Vec<&str> = self.0.split('_').collect();
@@ -42,3 +77,40 @@ impl fmt::Display for RecordLeaderboard {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_leaderboard_detects_global_scope() {
+        let lb = RecordLeaderboard("40l_global".to_string());
+        assert!(lb.is_global());
+        assert!(!lb.is_country());
+        assert_eq!(lb.country_code(), None);
+        assert!(matches!(lb.gamemode(), Some(RecordGamemode::FortyLines)));
+    }
+
+    #[test]
+    fn record_leaderboard_detects_country_scope() {
+        let lb = RecordLeaderboard("blitz_country_US".to_string());
+        assert!(!lb.is_global());
+        assert!(lb.is_country());
+        assert_eq!(lb.country_code().as_deref(), Some("US"));
+        assert!(matches!(lb.gamemode(), Some(RecordGamemode::Blitz)));
+        let lb = RecordLeaderboard("zenith_country_JP@2024w31".to_string());
+        assert_eq!(lb.country_code().as_deref(), Some("JP"));
+    }
+
+    #[test]
+    fn record_leaderboard_returns_none_if_malformed() {
+        let lb = RecordLeaderboard("unknown".to_string());
+        assert!(!lb.is_global());
+        assert!(!lb.is_country());
+        assert!(lb.gamemode().is_none());
+        let lb = RecordLeaderboard("_country_US".to_string());
+        assert!(!lb.is_country());
+        assert_eq!(lb.country_code(), None);
+        assert!(lb.gamemode().is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! A model for replay IDs.
 
 use crate::model::prelude::*;
+use std::str::FromStr;
 
 /// A replay's shortID.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash)]
@@ -12,6 +13,27 @@ impl ReplayId {
     pub fn replay_url(&self) -> String {
         format!("https://tetr.io/#R:{}", self)
     }
+
+    /// Returns the URL to watch the replay on the TETR.IO web client.
+    ///
+    /// The TETRA CHANNEL API does not provide any endpoint for replay data or metadata,
+    /// so this URL is the only way to access the replay.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::model::util::ReplayId;
+    /// let id: ReplayId = "VAF76VXt1Jr2Xhtx".parse().unwrap();
+    /// assert_eq!(id.web_url(), "https://tetr.io/#R:VAF76VXt1Jr2Xhtx");
+    /// ```
+    pub fn web_url(&self) -> String {
+        self.replay_url()
+    }
+
+    /// Returns the replay's shortID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 impl AsRef<ReplayId> for ReplayId {
@@ -25,3 +47,79 @@ impl fmt::Display for ReplayId {
         write!(f, "{}", self.0)
     }
 }
+
+impl FromStr for ReplayId {
+    type Err = ParseReplayIdError;
+
+    /// Parses a replay's shortID or a replay URL into a [`ReplayId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::model::util::ReplayId;
+    /// let id: ReplayId = "VAF76VXt1Jr2Xhtx".parse().unwrap();
+    /// assert_eq!(id.as_str(), "VAF76VXt1Jr2Xhtx");
+    ///
+    /// let id: ReplayId = "https://tetr.io/#R:VAF76VXt1Jr2Xhtx".parse().unwrap();
+    /// assert_eq!(id.as_str(), "VAF76VXt1Jr2Xhtx");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseReplayIdError`] if the shortID is empty
+    /// or contains characters other than ASCII alphanumerics, `-` and `_`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s
+            .strip_prefix("https://tetr.io/")
+            .unwrap_or(s)
+            .trim_start_matches('#');
+        let id = id
+            .strip_prefix("R:")
+            .or_else(|| id.strip_prefix("r:"))
+            .unwrap_or(id);
+        if !id.is_empty()
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            Ok(Self(id.to_string()))
+        } else {
+            Err(ParseReplayIdError(s.to_string()))
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`ReplayId`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseReplayIdError(String);
+
+impl std::error::Error for ParseReplayIdError {}
+
+impl fmt::Display for ParseReplayIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid replay ID `{}`", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_id_replay_url_round_trips() {
+        let id: ReplayId = "VAF76VXt1Jr2Xhtx".parse().unwrap();
+        assert_eq!(id.replay_url(), "https://tetr.io/#R:VAF76VXt1Jr2Xhtx");
+        assert_eq!(id.web_url(), id.replay_url());
+        assert_eq!(id.replay_url().parse::<ReplayId>().unwrap(), id);
+        assert_eq!("#r:VAF76VXt1Jr2Xhtx".parse::<ReplayId>().unwrap(), id);
+        assert_eq!(id.to_string().parse::<ReplayId>().unwrap(), id);
+    }
+
+    #[test]
+    fn replay_id_rejects_malformed_id() {
+        assert!("".parse::<ReplayId>().is_err());
+        assert!("https://tetr.io/#R:".parse::<ReplayId>().is_err());
+        let err = "VAF76 VXt1".parse::<ReplayId>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid replay ID `VAF76 VXt1`");
+    }
+}
@@ -1,6 +1,7 @@
 //! A model for timestamp.
 
 use crate::{model::prelude::*, util::to_unix_ts};
+use chrono::{DateTime, SecondsFormat, Utc};
 
 /// A timestamp string.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash)]
@@ -13,6 +14,32 @@ impl Timestamp {
         Self(ts)
     }
 
+    /// Creates a new `Timestamp` from the UNIX timestamp in milliseconds.
+    ///
+    /// The string is formatted in RFC 3339 with milliseconds, as the API does.
+    /// (e.g. `2022-07-26T17:35:23.988Z`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::model::util::Timestamp;
+    /// let ts = Timestamp::from_unix_ms(1658856923988);
+    /// assert_eq!(ts.to_string(), "2022-07-26T17:35:23.988Z");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given timestamp is out of range.
+    pub fn from_unix_ms(ms: i64) -> Self {
+        let dt = DateTime::from_timestamp_millis(ms).expect("The timestamp is out of range.");
+        Self(dt.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+
+    /// Creates a new `Timestamp` of the current time.
+    pub fn now() -> Self {
+        Self::from_unix_ms(Utc::now().timestamp_millis())
+    }
+
     /// Returns the UNIX timestamp.
     ///
     /// # Panics
@@ -21,6 +48,17 @@ impl Timestamp {
     pub fn unix_ts(&self) -> i64 {
         to_unix_ts(&self.0)
     }
+
+    /// Returns the UNIX timestamp in milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if failed to parse the given string.
+    pub fn unix_ms(&self) -> i64 {
+        DateTime::parse_from_rfc3339(&self.0)
+            .expect("Failed to parse the given string.")
+            .timestamp_millis()
+    }
 }
 
 impl AsRef<Timestamp> for Timestamp {
@@ -34,3 +72,25 @@ impl fmt::Display for Timestamp {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_round_trips_between_ms_and_string() {
+        let ts = Timestamp::from_unix_ms(1658856923988);
+        assert_eq!(ts.to_string(), "2022-07-26T17:35:23.988Z");
+        assert_eq!(ts.unix_ms(), 1658856923988);
+        assert_eq!(ts.unix_ts(), 1658856923);
+        let ts = Timestamp::new("2022-07-26T17:35:23.988Z".to_string());
+        assert_eq!(Timestamp::from_unix_ms(ts.unix_ms()), ts);
+    }
+
+    #[test]
+    fn timestamp_now_returns_current_time() {
+        let before = Utc::now().timestamp_millis();
+        let now = Timestamp::now().unix_ms();
+        assert!(before <= now && now <= Utc::now().timestamp_millis());
+    }
+}
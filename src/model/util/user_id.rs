@@ -1,6 +1,7 @@
 //! A model for user IDs,
 
-use crate::model::prelude::*;
+use crate::{model::prelude::*, util::is_valid_user_id};
+use std::str::FromStr;
 
 /// A user's internal ID.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash)]
@@ -14,6 +15,18 @@ impl UserId {
     pub fn id(&self) -> &str {
         &self.0
     }
+
+    /// Returns the user's internal ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the user ID is valid.
+    ///
+    /// A valid user ID is 24 lowercase hexadecimal characters.
+    pub fn is_valid(&self) -> bool {
+        is_valid_user_id(&self.0)
+    }
 }
 
 impl AsRef<UserId> for UserId {
@@ -27,3 +40,64 @@ impl fmt::Display for UserId {
         write!(f, "{}", self.0)
     }
 }
+
+impl FromStr for UserId {
+    type Err = ParseUserIdError;
+
+    /// Parses a string into a [`UserId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetr_ch::model::util::UserId;
+    /// let id: UserId = "621db46d1d638ea850be2aa0".parse().unwrap();
+    /// assert_eq!(id.as_str(), "621db46d1d638ea850be2aa0");
+    /// assert!("rinrin-rs".parse::<UserId>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseUserIdError`] if the string is not 24 lowercase hexadecimal characters.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if is_valid_user_id(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(ParseUserIdError(s.to_string()))
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`UserId`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseUserIdError(String);
+
+impl std::error::Error for ParseUserIdError {}
+
+impl fmt::Display for ParseUserIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid user ID `{}`", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_id_parses_valid_id() {
+        let id: UserId = "621db46d1d638ea850be2aa0".parse().unwrap();
+        assert!(id.is_valid());
+        assert_eq!(id.as_str(), "621db46d1d638ea850be2aa0");
+        assert_eq!(id.to_string(), "621db46d1d638ea850be2aa0");
+    }
+
+    #[test]
+    fn user_id_rejects_malformed_id() {
+        let err = "621DB46D1D638EA850BE2AA0".parse::<UserId>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid user ID `621DB46D1D638EA850BE2AA0`"
+        );
+        assert!(!UserId("rinrin-rs".to_string()).is_valid());
+    }
+}
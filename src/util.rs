@@ -2,17 +2,68 @@
 
 use crate::model::util::Timestamp;
 use chrono::DateTime;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::Deserialize;
 use serde_json::Value;
 
+pub mod clock;
+pub mod country;
+pub mod league;
+
 /// Converts the given XP to the level.
+///
+/// The formula is the one given in the [API document](https://tetr.io/about/api/#usersuser):
+/// `level = floor((xp / 500) ^ 0.6 + xp / (5000 + max(0, xp - 4000000) / 5000) + 1)`.
+///
+/// The `level` methods of the models call this function on every call,
+/// so it can be used to compute the levels in advance, e.g. for large leaderboards.
 pub fn xp_to_level(xp: f64) -> u32 {
     // (xp/500)^0.6 + (xp / (5000 + max(0, xp-4000000) / 5000)) + 1
     ((xp / 500.).powf(0.6) + (xp / (5000. + max_f64(0., xp - 4000000.) / 5000.)) + 1.).floor()
         as u32
 }
 
+/// Returns the minimum XP required to reach the given level.
+///
+/// This is the inverse of [`xp_to_level`],
+/// so it can be used to display the XP needed for the next level.
+/// `0.0` is returned for the level 0 and 1.
+///
+/// # Examples
+///
+/// ```
+/// # use tetr_ch::util::{xp_for_level, xp_to_level};
+/// let xp = 20000.;
+/// let next = xp_for_level(xp_to_level(xp) + 1);
+/// assert!(next > xp);
+/// assert_eq!(xp_to_level(next), xp_to_level(xp) + 1);
+/// ```
+pub fn xp_for_level(level: u32) -> f64 {
+    if level <= 1 {
+        return 0.;
+    }
+    // The formula has no closed-form inverse,
+    // so search the XP by bisection since it is monotonically increasing.
+    let mut low = 0.;
+    let mut high = 1.;
+    while xp_to_level(high) < level {
+        low = high;
+        high *= 2.;
+    }
+    for _ in 0..128 {
+        let mid = (low + high) / 2.;
+        if mid <= low || high <= mid {
+            break;
+        }
+        if xp_to_level(mid) < level {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    high
+}
+
 /// Compares and returns the maximum of two 64bit floats`.
 fn max_f64(v1: f64, v2: f64) -> f64 {
     if v1 < v2 {
@@ -51,6 +102,27 @@ where
     }
 }
 
+/// Deserializes from the given number or numeric string to `f64`.
+///
+/// Some caches may return the numbers as strings,
+/// so both of `12345.678` and `"12345.678"` are accepted.
+pub(crate) fn deserialize_string_or_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrF64 {
+        F64(f64),
+        String(String),
+    }
+
+    match StringOrF64::deserialize(deserializer)? {
+        StringOrF64::F64(v) => Ok(v),
+        StringOrF64::String(s) => s.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
 /// # Panics
 ///
 /// Panics with a message "The limit must be between 1 and 100, but got X."
@@ -63,9 +135,26 @@ pub(crate) fn validate_limit(value: u8) {
     );
 }
 
-/// Encode the given string for URLs.
+/// Whether the given string is a valid user ID.
+///
+/// A user ID is 24 lowercase hexadecimal characters.
+pub(crate) fn is_valid_user_id(id: &str) -> bool {
+    id.len() == 24 && id.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// The characters to percent-encode in a path segment.
+///
+/// The unreserved characters except `.` (RFC 3986) are left as is,
+/// so usernames such as `rinrin-rs` stay readable.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'~');
+
+/// Encode the given string for a path segment of URLs.
+///
+/// `.` is replaced with a space,
+/// so that the segment is never treated as `.` or `..`.
+/// Query values must not be encoded with this, since they are encoded when the request is built.
 pub(crate) fn encode(input: impl ToString) -> String {
-    utf8_percent_encode(&input.to_string().replace('.', " "), NON_ALPHANUMERIC).to_string()
+    utf8_percent_encode(&input.to_string().replace('.', " "), PATH_SEGMENT).to_string()
 }
 
 #[cfg(test)]
@@ -82,6 +171,47 @@ mod tests {
         assert_eq!(xp_to_level(8388608.), 1770);
     }
 
+    #[test]
+    fn xp_to_level_matches_known_levels() {
+        let known = [
+            (100., 1),
+            (500., 2),
+            (10000., 9),
+            (100000., 45),
+            (1000000., 296),
+            (10000000., 1994),
+            (50000000., 4522),
+        ];
+        for (xp, level) in known {
+            assert_eq!(xp_to_level(xp), level, "xp: {}", xp);
+        }
+    }
+
+    #[test]
+    fn xp_to_level_is_continuous_at_high_xp_term() {
+        // The `max(0, xp - 4000000)` term starts to take effect at 4,000,000 XP.
+        assert_eq!(xp_to_level(3999999.), 1020);
+        assert_eq!(xp_to_level(4000000.), 1020);
+        assert_eq!(xp_to_level(4000001.), 1020);
+        let mut prev = 0;
+        for xp in (3_900_000..4_100_000).step_by(1000) {
+            let level = xp_to_level(xp as f64);
+            assert!(level == prev || level == prev + 1 || prev == 0);
+            prev = level;
+        }
+    }
+
+    #[test]
+    fn xp_for_level_returns_first_xp_of_level() {
+        assert_eq!(xp_for_level(0), 0.);
+        assert_eq!(xp_for_level(1), 0.);
+        for level in [2, 5, 19, 96, 1770, 5000] {
+            let xp = xp_for_level(level);
+            assert_eq!(xp_to_level(xp), level);
+            assert_eq!(xp_to_level(xp - 0.001), level - 1);
+        }
+    }
+
     #[test]
     fn max_f64_returns_v1_if_v1_is_max() {
         let v1 = -2.;
@@ -140,9 +270,55 @@ mod tests {
         validate_limit(101);
     }
 
+    #[test]
+    fn is_valid_user_id_accepts_valid_id() {
+        assert!(is_valid_user_id("621db46d1d638ea850be2aa0"));
+    }
+
+    #[test]
+    fn is_valid_user_id_rejects_malformed_id() {
+        assert!(!is_valid_user_id("rinrin-rs"));
+        assert!(!is_valid_user_id("621DB46D1D638EA850BE2AA0"));
+        assert!(!is_valid_user_id("621db46d1d638ea850be2aa"));
+        assert!(!is_valid_user_id("621db46d1d638ea850be2aa0f"));
+        assert!(!is_valid_user_id("621db46d1d638ea850be2aag"));
+    }
+
+    #[test]
+    fn deserialize_string_or_f64_accepts_number_and_string() {
+        #[derive(Deserialize)]
+        struct S {
+            #[serde(deserialize_with = "deserialize_string_or_f64")]
+            v: f64,
+        }
+        let from_number: S = serde_json::from_value(json!({ "v": 12345.678 })).unwrap();
+        let from_string: S = serde_json::from_value(json!({ "v": "12345.678" })).unwrap();
+        let from_int: S = serde_json::from_value(json!({ "v": -1 })).unwrap();
+        assert_eq!(from_number.v, 12345.678);
+        assert_eq!(from_string.v, 12345.678);
+        assert_eq!(from_int.v, -1.);
+        assert!(serde_json::from_value::<S>(json!({ "v": "abc" })).is_err());
+        assert!(serde_json::from_value::<S>(json!({ "v": null })).is_err());
+    }
+
     #[test]
     fn encode_encodes_str() {
         assert_eq!(encode("Hello, world!"), "Hello%2C%20world%21");
         assert_eq!(encode("."), "%20");
     }
+
+    #[test]
+    fn encode_keeps_username_safe_characters() {
+        assert_eq!(encode("rinrin-rs"), "rinrin-rs");
+        assert_eq!(encode("a_b~c"), "a_b~c");
+        assert_eq!(
+            encode("621db46d1d638ea850be2aa0"),
+            "621db46d1d638ea850be2aa0"
+        );
+        assert_eq!(
+            encode("user_621db46d1d638ea850be2aa0"),
+            "user_621db46d1d638ea850be2aa0"
+        );
+        assert_eq!(encode("../a/b"), "%20%20%2Fa%2Fb");
+    }
 }
@@ -0,0 +1,42 @@
+//! Utilities for the time source.
+
+use chrono::Utc;
+
+/// A source of the current time.
+///
+/// This abstracts the time for the time-dependent features (e.g. [`CacheData::is_expired_with`](crate::model::cache::CacheData::is_expired_with)),
+/// so they can be tested deterministically with a [`FixedClock`].
+/// To use a clock in the client, see [`Client::with_clock`](crate::client::Client::with_clock).
+pub trait Clock {
+    /// Returns the current UNIX timestamp in milliseconds.
+    fn now_ms(&self) -> i64;
+}
+
+/// A [`Clock`] that returns the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> i64 {
+        Utc::now().timestamp_millis()
+    }
+}
+
+/// A [`Clock`] that always returns the specified time.
+///
+/// # Examples
+///
+/// ```
+/// use tetr_ch::util::clock::{Clock, FixedClock};
+///
+/// let clock = FixedClock(1661710769000);
+/// assert_eq!(clock.now_ms(), 1661710769000);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FixedClock(pub i64);
+
+impl Clock for FixedClock {
+    fn now_ms(&self) -> i64 {
+        self.0
+    }
+}
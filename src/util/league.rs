@@ -0,0 +1,57 @@
+//! Utilities for TETRA LEAGUE.
+
+/// Calculates the expected score of the player A against the player B.
+///
+/// The expected score is the chance (between 0 and 1) of the player A beating the player B,
+/// estimated from both players' GLIXARE scores (a % chance of beating an average player).
+///
+/// # Arguments
+///
+/// - `gxe_a` - The GLIXARE score of the player A.
+/// - `gxe_b` - The GLIXARE score of the player B.
+///
+/// # Examples
+///
+/// ```
+/// use tetr_ch::util::league::expected_score;
+///
+/// assert_eq!(expected_score(50., 50.), 0.5);
+/// assert_eq!(expected_score(70., 50.), 0.7);
+/// ```
+pub fn expected_score(gxe_a: f64, gxe_b: f64) -> f64 {
+    let (p_a, p_b) = (gxe_a / 100., gxe_b / 100.);
+    let a_wins = p_a * (1. - p_b);
+    let b_wins = p_b * (1. - p_a);
+    if a_wins + b_wins == 0. {
+        return 0.5;
+    }
+    a_wins / (a_wins + b_wins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_score_returns_half_for_symmetric_inputs() {
+        assert_eq!(expected_score(50., 50.), 0.5);
+        assert_eq!(expected_score(87.3, 87.3), 0.5);
+        assert_eq!(expected_score(0., 0.), 0.5);
+        assert_eq!(expected_score(100., 100.), 0.5);
+    }
+
+    #[test]
+    fn expected_score_favors_higher_gxe() {
+        assert!((expected_score(70., 50.) - 0.7).abs() < 1e-9);
+        assert!((expected_score(50., 70.) - 0.3).abs() < 1e-9);
+        assert!(expected_score(90., 60.) > 0.8);
+        assert_eq!(expected_score(100., 60.), 1.);
+        assert_eq!(expected_score(0., 60.), 0.);
+    }
+
+    #[test]
+    fn expected_score_is_complementary() {
+        let (a, b) = (81.2, 43.9);
+        assert!((expected_score(a, b) + expected_score(b, a) - 1.).abs() < 1e-9);
+    }
+}
@@ -3,6 +3,7 @@ use tetr_ch::{
         leaderboard::{HistoricalLeaderboard, Leaderboard},
         news::NewsItems,
         records_leaderboard::RecordsLeaderboard,
+        user::User,
         user_records::UserRecords,
     },
     prelude::*,
@@ -10,6 +11,18 @@ use tetr_ch::{
 
 type Rsp<T> = Result<tetr_ch::model::response::Response<T>, tetr_ch::client::error::ResponseError>;
 
+#[tokio::test]
+async fn client_get_user_by_id_successes_if_valid_id() {
+    let _: Rsp<User> = Client::new()
+        .get_user_by_id("621db46d1d638ea850be2aa0")
+        .await;
+}
+
+#[tokio::test]
+async fn client_get_user_by_name_successes_if_username() {
+    let _: Rsp<User> = Client::new().get_user_by_name("RINRIN-RS").await;
+}
+
 #[tokio::test]
 async fn client_get_leaderboard_successes_if_valid_limit() {
     let criteria = user_leaderboard::SearchCriteria::new()
@@ -217,3 +230,27 @@ async fn client_get_news_latest_panics_if_limit_is_101() {
         )
         .await;
 }
+
+#[tokio::test]
+async fn client_records_in_range_successes() {
+    let _: Result<Vec<tetr_ch::model::summary::record::Record>, _> = Client::new()
+        .records_in_range("rinrin-rs", RecordGamemode::Blitz, 1722470400, 1725148799)
+        .await;
+}
+
+#[tokio::test]
+async fn client_get_season_champion_successes() {
+    let _ = Client::new().get_season_champion("1").await;
+}
+
+#[tokio::test]
+async fn client_usernames_for_successes() {
+    let _ = Client::new().usernames_for(&[]).await;
+}
+
+#[tokio::test]
+async fn client_get_profile_with_league_graph_successes() {
+    let _ = Client::new()
+        .get_profile_with_league_graph("rinrin-rs")
+        .await;
+}